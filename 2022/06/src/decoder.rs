@@ -0,0 +1,315 @@
+use std::{
+    collections::VecDeque,
+    fmt::Display,
+    io::{self, ErrorKind, Read},
+};
+
+use crate::marker::{
+    MarkerDetector, START_OF_MESSAGE_LENGTH, START_OF_PACKET_LENGTH,
+};
+
+const BUFFER_SIZE: usize = 4096;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum MarkerKind {
+    StartOfPacket,
+    StartOfMessage,
+}
+
+impl Display for MarkerKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match *self {
+            Self::StartOfPacket => write!(f, "start-of-packet"),
+            Self::StartOfMessage => write!(f, "start-of-message"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) struct Marker {
+    pub(crate) kind: MarkerKind,
+    pub(crate) offset: usize,
+}
+
+impl Display for Marker {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} marker at {}", self.kind, self.offset)
+    }
+}
+
+pub(crate) struct Decoder<R> {
+    reader: R,
+    buffer: Box<[u8; BUFFER_SIZE]>,
+    cursor: usize,
+    filled: usize,
+    offset: usize,
+    report_all: bool,
+    done: bool,
+    start_of_packet: MarkerDetector,
+    start_of_message: MarkerDetector,
+    found_start_of_packet: bool,
+    found_start_of_message: bool,
+    pending: VecDeque<Marker>,
+    whitespace: Vec<u8>,
+}
+
+impl<R: Read> Decoder<R> {
+    pub(crate) fn new(reader: R) -> Self {
+        Self {
+            reader,
+            buffer: Box::new([0; BUFFER_SIZE]),
+            cursor: 0,
+            filled: 0,
+            offset: 0,
+            report_all: false,
+            done: false,
+            start_of_packet: MarkerDetector::new(START_OF_PACKET_LENGTH),
+            start_of_message: MarkerDetector::new(START_OF_MESSAGE_LENGTH),
+            found_start_of_packet: false,
+            found_start_of_message: false,
+            pending: VecDeque::with_capacity(2),
+            whitespace: Vec::new(),
+        }
+    }
+
+    pub(crate) fn report_all(mut self, report_all: bool) -> Self {
+        self.report_all = report_all;
+        self
+    }
+
+    fn next_byte(&mut self) -> io::Result<Option<u8>> {
+        while self.cursor == self.filled {
+            match self.reader.read(&mut self.buffer[..]) {
+                Ok(0) => return Ok(None),
+                Ok(filled) => {
+                    self.cursor = 0;
+                    self.filled = filled;
+                }
+                Err(err) if err.kind() == ErrorKind::Interrupted => {}
+                Err(err) => return Err(err),
+            }
+        }
+        let byte = self.buffer[self.cursor];
+        self.cursor += 1;
+        Ok(Some(byte))
+    }
+
+    fn push(&mut self, byte: u8) {
+        self.offset += 1;
+        if self.start_of_packet.push(byte)
+            && (self.report_all || !self.found_start_of_packet)
+        {
+            self.found_start_of_packet = true;
+            self.pending.push_back(Marker {
+                kind: MarkerKind::StartOfPacket,
+                offset: self.offset,
+            });
+        }
+        if self.start_of_message.push(byte)
+            && (self.report_all || !self.found_start_of_message)
+        {
+            self.found_start_of_message = true;
+            self.pending.push_back(Marker {
+                kind: MarkerKind::StartOfMessage,
+                offset: self.offset,
+            });
+        }
+    }
+
+    fn is_finished(&self) -> bool {
+        !self.report_all
+            && self.found_start_of_packet
+            && self.found_start_of_message
+    }
+}
+
+impl<R: Read> Iterator for Decoder<R> {
+    type Item = io::Result<Marker>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if let Some(marker) = self.pending.pop_front() {
+                return Some(Ok(marker));
+            }
+            if self.done || self.is_finished() {
+                return None;
+            }
+            let byte = match self.next_byte() {
+                Ok(Some(byte)) if byte.is_ascii_whitespace() => {
+                    self.whitespace.push(byte);
+                    continue;
+                }
+                Ok(Some(byte)) => byte,
+                Ok(None) => {
+                    self.done = true;
+                    return None;
+                }
+                Err(err) => {
+                    self.done = true;
+                    return Some(Err(err));
+                }
+            };
+            for whitespace in std::mem::take(&mut self.whitespace) {
+                self.push(whitespace);
+            }
+            self.push(byte);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{input::Input, marker::find_marker};
+
+    struct ByteByByte<'a>(&'a [u8]);
+
+    impl Read for ByteByByte<'_> {
+        fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+            match self.0.split_first() {
+                Some((&byte, rest)) if !buf.is_empty() => {
+                    buf[0] = byte;
+                    self.0 = rest;
+                    Ok(1)
+                }
+                _ => Ok(0),
+            }
+        }
+    }
+
+    #[test]
+    fn test_decoder() {
+        struct TestCase {
+            input: &'static str,
+            expected: Vec<Marker>,
+        }
+        let marker = |kind, offset| Marker { kind, offset };
+        let test_cases = [
+            TestCase {
+                input: "mjqjpqmgbljsphdztnvjfqwrcgsmlb\n",
+                expected: vec![
+                    marker(MarkerKind::StartOfPacket, 7),
+                    marker(MarkerKind::StartOfMessage, 19),
+                ],
+            },
+            TestCase {
+                input: "bvwbjplbgvbhsrlpgdmjqwftvncz",
+                expected: vec![
+                    marker(MarkerKind::StartOfPacket, 5),
+                    marker(MarkerKind::StartOfMessage, 23),
+                ],
+            },
+            TestCase {
+                input: "abcdefghijklmn",
+                expected: vec![
+                    marker(MarkerKind::StartOfPacket, 4),
+                    marker(MarkerKind::StartOfMessage, 14),
+                ],
+            },
+            TestCase {
+                input: "abcabcabcabc",
+                expected: vec![],
+            },
+        ];
+        for tc in test_cases {
+            let result = Decoder::new(tc.input.as_bytes())
+                .collect::<io::Result<Vec<_>>>()
+                .unwrap();
+            assert_eq!(result, tc.expected);
+            let result = Decoder::new(ByteByByte(tc.input.as_bytes()))
+                .collect::<io::Result<Vec<_>>>()
+                .unwrap();
+            assert_eq!(result, tc.expected);
+        }
+    }
+
+    #[test]
+    fn test_decoder_counts_inner_line_endings() {
+        struct TestCase {
+            input: &'static str,
+            expected: Vec<Marker>,
+        }
+        let marker = |kind, offset| Marker { kind, offset };
+        let test_cases = [
+            TestCase {
+                input: "aab\ncdefghijklmnop\n",
+                expected: vec![
+                    marker(MarkerKind::StartOfPacket, 5),
+                    marker(MarkerKind::StartOfMessage, 15),
+                ],
+            },
+            TestCase {
+                input: "aaa\r\nbbb\r\ncdefghijklmnopqrs\r\n\r\n",
+                expected: vec![
+                    marker(MarkerKind::StartOfPacket, 6),
+                    marker(MarkerKind::StartOfMessage, 21),
+                ],
+            },
+            TestCase {
+                input: "abc\n\n",
+                expected: vec![],
+            },
+        ];
+        for tc in test_cases {
+            let result = Decoder::new(ByteByByte(tc.input.as_bytes()))
+                .collect::<io::Result<Vec<_>>>()
+                .unwrap();
+            assert_eq!(result, tc.expected);
+            let signal = tc.input.parse::<Input>().unwrap().signal;
+            let expected = [
+                (MarkerKind::StartOfPacket, START_OF_PACKET_LENGTH),
+                (MarkerKind::StartOfMessage, START_OF_MESSAGE_LENGTH),
+            ]
+            .into_iter()
+            .filter_map(|(kind, length)| {
+                find_marker(&signal, length).map(|offset| marker(kind, offset))
+            })
+            .collect::<Vec<_>>();
+            assert_eq!(result, expected);
+        }
+    }
+
+    #[test]
+    fn test_decoder_report_all() {
+        struct TestCase {
+            input: &'static str,
+            expected: Vec<Marker>,
+        }
+        let marker = |kind, offset| Marker { kind, offset };
+        let test_cases = [
+            TestCase {
+                input: "aabcde",
+                expected: vec![
+                    marker(MarkerKind::StartOfPacket, 5),
+                    marker(MarkerKind::StartOfPacket, 6),
+                ],
+            },
+            TestCase {
+                input: "abcdefghijklmnoo",
+                expected: vec![
+                    marker(MarkerKind::StartOfPacket, 4),
+                    marker(MarkerKind::StartOfPacket, 5),
+                    marker(MarkerKind::StartOfPacket, 6),
+                    marker(MarkerKind::StartOfPacket, 7),
+                    marker(MarkerKind::StartOfPacket, 8),
+                    marker(MarkerKind::StartOfPacket, 9),
+                    marker(MarkerKind::StartOfPacket, 10),
+                    marker(MarkerKind::StartOfPacket, 11),
+                    marker(MarkerKind::StartOfPacket, 12),
+                    marker(MarkerKind::StartOfPacket, 13),
+                    marker(MarkerKind::StartOfPacket, 14),
+                    marker(MarkerKind::StartOfMessage, 14),
+                    marker(MarkerKind::StartOfPacket, 15),
+                    marker(MarkerKind::StartOfMessage, 15),
+                ],
+            },
+        ];
+        for tc in test_cases {
+            let result = Decoder::new(ByteByByte(tc.input.as_bytes()))
+                .report_all(true)
+                .collect::<io::Result<Vec<_>>>()
+                .unwrap();
+            assert_eq!(result, tc.expected);
+        }
+    }
+}
@@ -2,7 +2,7 @@ use std::{convert::Infallible, str::FromStr};
 
 #[derive(Debug)]
 pub(crate) struct Input {
-    pub(crate) signal: Vec<char>,
+    pub(crate) signal: Vec<u8>,
 }
 
 impl FromStr for Input {
@@ -10,7 +10,7 @@ impl FromStr for Input {
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         Ok(Self {
-            signal: s.chars().collect(),
+            signal: s.trim_end().bytes().collect(),
         })
     }
 }
@@ -1,18 +1,35 @@
+mod decoder;
 mod input;
+mod marker;
 mod output;
 mod part1;
 mod part2;
 
+use std::io;
+
+use decoder::Decoder;
 use input::Input;
 
 fn main() {
+    let args = std::env::args().skip(1).collect::<Vec<_>>();
+    if args.first().map(String::as_str) == Some("stream") {
+        let report_all = args.iter().any(|arg| arg == "--all");
+        for marker in Decoder::new(io::stdin().lock()).report_all(report_all) {
+            let marker = marker.expect("Signal should be readable");
+            println!("{}", marker);
+        }
+        return;
+    }
+
     let input = include_str!("input.txt")
         .parse::<Input>()
         .expect("Input should be valid");
 
-    let output = part1::solve(&input);
+    let output = part1::solve(&input)
+        .expect("Signal should contain a start-of-packet marker");
     println!("Part 1: {}", output);
 
-    let output = part2::solve(&input);
+    let output = part2::solve(&input)
+        .expect("Signal should contain a start-of-message marker");
     println!("Part 2: {}", output);
 }
@@ -0,0 +1,101 @@
+use std::collections::VecDeque;
+
+pub(crate) const START_OF_PACKET_LENGTH: usize = 4;
+pub(crate) const START_OF_MESSAGE_LENGTH: usize = 14;
+
+#[derive(Debug, Clone)]
+pub(crate) struct MarkerDetector {
+    length: usize,
+    window: VecDeque<u8>,
+    counts: [usize; 256],
+    duplicates: usize,
+}
+
+impl MarkerDetector {
+    pub(crate) fn new(length: usize) -> Self {
+        Self {
+            length,
+            window: VecDeque::with_capacity(length + 1),
+            counts: [0; 256],
+            duplicates: 0,
+        }
+    }
+
+    pub(crate) fn push(&mut self, byte: u8) -> bool {
+        self.window.push_back(byte);
+        self.counts[byte as usize] += 1;
+        if self.counts[byte as usize] == 2 {
+            self.duplicates += 1;
+        }
+        if self.window.len() > self.length {
+            let outgoing = self.window.pop_front().unwrap() as usize;
+            if self.counts[outgoing] == 2 {
+                self.duplicates -= 1;
+            }
+            self.counts[outgoing] -= 1;
+        }
+        self.window.len() == self.length && self.duplicates == 0
+    }
+}
+
+pub(crate) fn find_marker(signal: &[u8], length: usize) -> Option<usize> {
+    if length == 0 {
+        return Some(0);
+    }
+    let mut detector = MarkerDetector::new(length);
+    signal
+        .iter()
+        .position(|&byte| detector.push(byte))
+        .map(|index| index + 1)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_find_marker() {
+        struct TestCase {
+            input: (&'static str, usize),
+            expected: Option<usize>,
+        }
+        let test_cases = [
+            TestCase {
+                input: ("mjqjpqmgbljsphdztnvjfqwrcgsmlb", 4),
+                expected: Some(7),
+            },
+            TestCase {
+                input: ("mjqjpqmgbljsphdztnvjfqwrcgsmlb", 14),
+                expected: Some(19),
+            },
+            TestCase {
+                input: ("abcd", 4),
+                expected: Some(4),
+            },
+            TestCase {
+                input: ("abc", 4),
+                expected: None,
+            },
+            TestCase {
+                input: ("aaaaaaaa", 2),
+                expected: None,
+            },
+            TestCase {
+                input: ("aaaaaaab", 2),
+                expected: Some(8),
+            },
+            TestCase {
+                input: ("abbbbbbb", 1),
+                expected: Some(1),
+            },
+            TestCase {
+                input: ("", 0),
+                expected: Some(0),
+            },
+        ];
+        for tc in test_cases {
+            let result = find_marker(tc.input.0.as_bytes(), tc.input.1);
+            assert_eq!(result, tc.expected);
+        }
+    }
+}
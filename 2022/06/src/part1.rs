@@ -1,24 +1,11 @@
-use crate::{input::Input, output::Output};
+use crate::{
+    input::Input,
+    marker::{START_OF_PACKET_LENGTH, find_marker},
+    output::Output,
+};
 
-pub(crate) fn solve(input: &Input) -> Output {
-    let length = 4;
-    let marker_index = input
-        .signal
-        .windows(length)
-        .enumerate()
-        .find(|(_, window)| {
-            for i in 0..(window.len() - 1) {
-                for j in (i + 1)..window.len() {
-                    if window[i] == window[j] {
-                        return false;
-                    }
-                }
-            }
-            true
-        })
-        .map(|(index, _)| index)
-        .unwrap();
-    Output::new(marker_index + length)
+pub(crate) fn solve(input: &Input) -> Option<Output> {
+    find_marker(&input.signal, START_OF_PACKET_LENGTH).map(Output::new)
 }
 
 #[cfg(test)]
@@ -29,24 +16,28 @@ mod tests {
     fn test_solve() {
         struct TestCase {
             input: Input,
-            output: Output,
+            output: Option<Output>,
         }
         let test_cases = [
             TestCase {
                 input: "bvwbjplbgvbhsrlpgdmjqwftvncz".parse().unwrap(),
-                output: Output::new(5),
+                output: Some(Output::new(5)),
             },
             TestCase {
                 input: "nppdvjthqldpwncqszvftbrmjlhg".parse().unwrap(),
-                output: Output::new(6),
+                output: Some(Output::new(6)),
             },
             TestCase {
                 input: "nznrnfrfntjfmvfwmzdfjlvtqnbhcprsg".parse().unwrap(),
-                output: Output::new(10),
+                output: Some(Output::new(10)),
             },
             TestCase {
                 input: "zcfzfwzzqfrljwzlrfnpqdbhtmscgvjw".parse().unwrap(),
-                output: Output::new(11),
+                output: Some(Output::new(11)),
+            },
+            TestCase {
+                input: "abcabcabcabcabcabc".parse().unwrap(),
+                output: None,
             },
         ];
         for tc in test_cases {
@@ -1,24 +1,11 @@
-use crate::{input::Input, output::Output};
+use crate::{
+    input::Input,
+    marker::{START_OF_MESSAGE_LENGTH, find_marker},
+    output::Output,
+};
 
-pub(crate) fn solve(input: &Input) -> Output {
-    let length = 14;
-    let marker_index = input
-        .signal
-        .windows(length)
-        .enumerate()
-        .find(|(_, window)| {
-            for i in 0..(window.len() - 1) {
-                for j in (i + 1)..window.len() {
-                    if window[i] == window[j] {
-                        return false;
-                    }
-                }
-            }
-            true
-        })
-        .map(|(index, _)| index)
-        .unwrap();
-    Output::new(marker_index + length)
+pub(crate) fn solve(input: &Input) -> Option<Output> {
+    find_marker(&input.signal, START_OF_MESSAGE_LENGTH).map(Output::new)
 }
 
 #[cfg(test)]
@@ -29,28 +16,32 @@ mod tests {
     fn test_solve() {
         struct TestCase {
             input: Input,
-            output: Output,
+            output: Option<Output>,
         }
         let test_cases = [
             TestCase {
                 input: "mjqjpqmgbljsphdztnvjfqwrcgsmlb".parse().unwrap(),
-                output: Output::new(19),
+                output: Some(Output::new(19)),
             },
             TestCase {
                 input: "bvwbjplbgvbhsrlpgdmjqwftvncz".parse().unwrap(),
-                output: Output::new(23),
+                output: Some(Output::new(23)),
             },
             TestCase {
                 input: "nppdvjthqldpwncqszvftbrmjlhg".parse().unwrap(),
-                output: Output::new(23),
+                output: Some(Output::new(23)),
             },
             TestCase {
                 input: "nznrnfrfntjfmvfwmzdfjlvtqnbhcprsg".parse().unwrap(),
-                output: Output::new(29),
+                output: Some(Output::new(29)),
             },
             TestCase {
                 input: "zcfzfwzzqfrljwzlrfnpqdbhtmscgvjw".parse().unwrap(),
-                output: Output::new(26),
+                output: Some(Output::new(26)),
+            },
+            TestCase {
+                input: "abcabcabcabcabcabc".parse().unwrap(),
+                output: None,
             },
         ];
         for tc in test_cases {
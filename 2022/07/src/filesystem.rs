@@ -0,0 +1,155 @@
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub(crate) struct NodeId(usize);
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) enum NodeKind {
+    Directory { children: Vec<NodeId> },
+    File { size: u64 },
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct Node {
+    pub(crate) name: String,
+    pub(crate) parent: Option<NodeId>,
+    pub(crate) kind: NodeKind,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct FileSystem {
+    nodes: Vec<Node>,
+}
+
+impl Default for FileSystem {
+    fn default() -> Self {
+        Self {
+            nodes: vec![Node {
+                name: "/".to_string(),
+                parent: None,
+                kind: NodeKind::Directory {
+                    children: Vec::new(),
+                },
+            }],
+        }
+    }
+}
+
+impl FileSystem {
+    pub(crate) fn root(&self) -> NodeId {
+        NodeId(0)
+    }
+
+    pub(crate) fn node(&self, id: NodeId) -> &Node {
+        &self.nodes[id.0]
+    }
+
+    pub(crate) fn parent(&self, id: NodeId) -> Option<NodeId> {
+        self.node(id).parent
+    }
+
+    pub(crate) fn children(&self, id: NodeId) -> &[NodeId] {
+        match self.node(id).kind {
+            NodeKind::Directory { ref children } => children,
+            NodeKind::File { .. } => &[],
+        }
+    }
+
+    pub(crate) fn child(&self, id: NodeId, name: &str) -> Option<NodeId> {
+        self.children(id)
+            .iter()
+            .copied()
+            .find(|&child| self.node(child).name == name)
+    }
+
+    pub(crate) fn is_directory(&self, id: NodeId) -> bool {
+        matches!(self.node(id).kind, NodeKind::Directory { .. })
+    }
+
+    pub(crate) fn insert(
+        &mut self,
+        parent: NodeId,
+        name: &str,
+        kind: NodeKind,
+    ) -> NodeId {
+        let id = NodeId(self.nodes.len());
+        self.nodes.push(Node {
+            name: name.to_string(),
+            parent: Some(parent),
+            kind,
+        });
+        match self.nodes[parent.0].kind {
+            NodeKind::Directory { ref mut children } => children.push(id),
+            NodeKind::File { .. } => panic!("Parent should be a directory"),
+        }
+        id
+    }
+
+    pub(crate) fn total_size(&self) -> u64 {
+        self.sizes()[self.root().0]
+    }
+
+    pub(crate) fn sizes(&self) -> Vec<u64> {
+        let mut sizes = self
+            .nodes
+            .iter()
+            .map(|node| match node.kind {
+                NodeKind::Directory { .. } => 0,
+                NodeKind::File { size } => size,
+            })
+            .collect::<Vec<_>>();
+        for (id, node) in self.nodes.iter().enumerate().rev() {
+            if let Some(parent) = node.parent {
+                sizes[parent.0] += sizes[id];
+            }
+        }
+        sizes
+    }
+
+    pub(crate) fn directory_sizes(&self) -> impl Iterator<Item = u64> {
+        let sizes = self.sizes();
+        (0..self.nodes.len())
+            .filter(|&id| self.is_directory(NodeId(id)))
+            .map(move |id| sizes[id])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn filesystem_sizes() {
+        let mut filesystem = FileSystem::default();
+        let root = filesystem.root();
+        let a = filesystem.insert(
+            root,
+            "a",
+            NodeKind::Directory {
+                children: Vec::new(),
+            },
+        );
+        let e = filesystem.insert(
+            a,
+            "e",
+            NodeKind::Directory {
+                children: Vec::new(),
+            },
+        );
+        filesystem.insert(e, "i", NodeKind::File { size: 584 });
+        filesystem.insert(a, "f", NodeKind::File { size: 29116 });
+        filesystem.insert(root, "b.txt", NodeKind::File { size: 14848514 });
+
+        assert_eq!(filesystem.child(root, "a"), Some(a));
+        assert_eq!(filesystem.child(a, "e"), Some(e));
+        assert_eq!(filesystem.child(root, "e"), None);
+        assert_eq!(filesystem.parent(e), Some(a));
+        assert_eq!(filesystem.parent(root), None);
+        assert_eq!(
+            filesystem.sizes(),
+            vec![14878214, 29700, 584, 584, 29116, 14848514]
+        );
+        assert_eq!(
+            filesystem.directory_sizes().collect::<Vec<_>>(),
+            vec![14878214, 29700, 584]
+        );
+    }
+}
@@ -0,0 +1,257 @@
+use std::{error::Error, fmt::Display, num::ParseIntError, str::FromStr};
+
+use crate::filesystem::{FileSystem, NodeId, NodeKind};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) enum ParseInputError {
+    UnknownCommand { line: usize, command: String },
+    MissingDirectory { line: usize },
+    ParentOfRoot { line: usize },
+    NotADirectory { line: usize, name: String },
+    OutputWithoutListing { line: usize },
+    InvalidEntry { line: usize, entry: String },
+    ParseSize { line: usize, source: ParseIntError },
+    ConflictingEntry { line: usize, name: String },
+}
+
+impl Display for ParseInputError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match *self {
+            Self::UnknownCommand { line, ref command } => {
+                write!(f, "unknown command on line {}: {}", line, command)
+            }
+            Self::MissingDirectory { line } => {
+                write!(f, "missing directory for cd on line {}", line)
+            }
+            Self::ParentOfRoot { line } => {
+                write!(f, "cannot cd above root on line {}", line)
+            }
+            Self::NotADirectory { line, ref name } => {
+                write!(f, "cannot cd into file {} on line {}", name, line)
+            }
+            Self::OutputWithoutListing { line } => {
+                write!(f, "output without ls command on line {}", line)
+            }
+            Self::InvalidEntry { line, ref entry } => {
+                write!(f, "invalid ls entry on line {}: {}", line, entry)
+            }
+            Self::ParseSize { line, ref source } => {
+                write!(f, "failed to parse size on line {}: {}", line, source)
+            }
+            Self::ConflictingEntry { line, ref name } => {
+                write!(f, "conflicting entry {} on line {}", name, line)
+            }
+        }
+    }
+}
+
+impl Error for ParseInputError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match *self {
+            Self::ParseSize { ref source, .. } => Some(source),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct Input {
+    pub(crate) filesystem: FileSystem,
+}
+
+fn directory() -> NodeKind {
+    NodeKind::Directory {
+        children: Vec::new(),
+    }
+}
+
+impl FromStr for Input {
+    type Err = ParseInputError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut filesystem = FileSystem::default();
+        let mut cwd = filesystem.root();
+        let mut listing = false;
+        for (index, text) in s.lines().enumerate() {
+            let line = index + 1;
+            if let Some(command) = text.strip_prefix("$ ") {
+                listing = false;
+                match command.split_whitespace().collect::<Vec<_>>()[..] {
+                    ["cd"] => {
+                        return Err(ParseInputError::MissingDirectory { line });
+                    }
+                    ["cd", "/"] => cwd = filesystem.root(),
+                    ["cd", ".."] => {
+                        cwd = filesystem
+                            .parent(cwd)
+                            .ok_or(ParseInputError::ParentOfRoot { line })?;
+                    }
+                    ["cd", name] => {
+                        cwd = enter(&mut filesystem, cwd, name, line)?;
+                    }
+                    ["ls"] => listing = true,
+                    _ => {
+                        return Err(ParseInputError::UnknownCommand {
+                            line,
+                            command: command.to_string(),
+                        });
+                    }
+                }
+                continue;
+            }
+            if text.trim().is_empty() {
+                continue;
+            }
+            if !listing {
+                return Err(ParseInputError::OutputWithoutListing { line });
+            }
+            let Some((info, name)) = text.split_once(' ') else {
+                return Err(ParseInputError::InvalidEntry {
+                    line,
+                    entry: text.to_string(),
+                });
+            };
+            let kind = match info {
+                "dir" => directory(),
+                size => NodeKind::File {
+                    size: size.parse().map_err(|source| {
+                        ParseInputError::ParseSize { line, source }
+                    })?,
+                },
+            };
+            let Some(existing) = filesystem.child(cwd, name) else {
+                filesystem.insert(cwd, name, kind);
+                continue;
+            };
+            match (&filesystem.node(existing).kind, &kind) {
+                (NodeKind::Directory { .. }, NodeKind::Directory { .. }) => {}
+                (NodeKind::File { size: a }, NodeKind::File { size: b })
+                    if a == b => {}
+                _ => {
+                    return Err(ParseInputError::ConflictingEntry {
+                        line,
+                        name: name.to_string(),
+                    });
+                }
+            }
+        }
+        Ok(Self { filesystem })
+    }
+}
+
+fn enter(
+    filesystem: &mut FileSystem,
+    cwd: NodeId,
+    name: &str,
+    line: usize,
+) -> Result<NodeId, ParseInputError> {
+    match filesystem.child(cwd, name) {
+        Some(child) if filesystem.is_directory(child) => Ok(child),
+        Some(_) => Err(ParseInputError::NotADirectory {
+            line,
+            name: name.to_string(),
+        }),
+        None => Ok(filesystem.insert(cwd, name, directory())),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("input.txt");
+
+    #[test]
+    fn input_from_str() {
+        let input = EXAMPLE.parse::<Input>().unwrap();
+        let filesystem = &input.filesystem;
+        let root = filesystem.root();
+        let names = |id| {
+            filesystem
+                .children(id)
+                .iter()
+                .map(|&child| filesystem.node(child).name.as_str())
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(names(root), vec!["a", "b.txt", "c.dat", "d"]);
+        let a = filesystem.child(root, "a").unwrap();
+        assert_eq!(names(a), vec!["e", "f", "g", "h.lst"]);
+        let e = filesystem.child(a, "e").unwrap();
+        assert_eq!(filesystem.parent(e), Some(a));
+        assert_eq!(
+            filesystem.node(filesystem.child(e, "i").unwrap()).kind,
+            NodeKind::File { size: 584 }
+        );
+        assert_eq!(
+            filesystem.directory_sizes().collect::<Vec<_>>(),
+            vec![48381165, 94853, 24933642, 584]
+        );
+    }
+
+    #[test]
+    fn input_from_str_repeated_listing() {
+        let transcript = "$ ls\ndir a\n1 b\n$ cd a\n$ cd ..\n$ ls\ndir a\n1 b";
+        let input = transcript.parse::<Input>().unwrap();
+        assert_eq!(input.filesystem.children(input.filesystem.root()).len(), 2);
+    }
+
+    #[test]
+    fn input_from_str_error() {
+        struct TestCase {
+            input: &'static str,
+            output: String,
+        }
+        let test_cases = [
+            TestCase {
+                input: "$ cd /\n$ cd ..",
+                output: "cannot cd above root on line 2".to_string(),
+            },
+            TestCase {
+                input: "$ cd a\n$ cd ..\n$ cd ..",
+                output: "cannot cd above root on line 3".to_string(),
+            },
+            TestCase {
+                input: "$ cd",
+                output: "missing directory for cd on line 1".to_string(),
+            },
+            TestCase {
+                input: "$ rm -rf /",
+                output: "unknown command on line 1: rm -rf /".to_string(),
+            },
+            TestCase {
+                input: "$ ls\n10 a\n$ cd a",
+                output: "cannot cd into file a on line 3".to_string(),
+            },
+            TestCase {
+                input: "$ cd /\ndir a",
+                output: "output without ls command on line 2".to_string(),
+            },
+            TestCase {
+                input: "dir a",
+                output: "output without ls command on line 1".to_string(),
+            },
+            TestCase {
+                input: "$ ls\nfile",
+                output: "invalid ls entry on line 2: file".to_string(),
+            },
+            TestCase {
+                input: "$ ls\nten a",
+                output: "failed to parse size on line 2: invalid digit found \
+                         in string"
+                    .to_string(),
+            },
+            TestCase {
+                input: "$ ls\n10 a\n$ ls\n20 a",
+                output: "conflicting entry a on line 4".to_string(),
+            },
+            TestCase {
+                input: "$ ls\ndir a\n$ ls\n20 a",
+                output: "conflicting entry a on line 4".to_string(),
+            },
+        ];
+        for tc in test_cases {
+            let err = tc.input.parse::<Input>().unwrap_err();
+            assert_eq!(err.to_string(), tc.output);
+        }
+    }
+}
@@ -0,0 +1,45 @@
+use crate::{input::Input, output::Output};
+
+const MAX_DIRECTORY_SIZE: u64 = 100_000;
+
+pub(crate) fn solve(input: &Input) -> Output {
+    Output {
+        size: input
+            .filesystem
+            .directory_sizes()
+            .filter(|&size| size <= MAX_DIRECTORY_SIZE)
+            .sum(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_solve() {
+        struct TestCase {
+            input: Input,
+            output: Output,
+        }
+        let test_cases = [
+            TestCase {
+                input: include_str!("input.txt").parse().unwrap(),
+                output: Output { size: 95437 },
+            },
+            TestCase {
+                input: "$ cd /\n$ ls\n100001 a".parse().unwrap(),
+                output: Output { size: 0 },
+            },
+            TestCase {
+                input: "$ ls\ndir a\n$ cd a\n$ ls\ndir b\n$ cd b\n$ ls\n10 c"
+                    .parse()
+                    .unwrap(),
+                output: Output { size: 30 },
+            },
+        ];
+        for tc in test_cases {
+            assert_eq!(solve(&tc.input), tc.output);
+        }
+    }
+}
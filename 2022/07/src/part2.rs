@@ -0,0 +1,49 @@
+use crate::{input::Input, output::Output};
+
+const DISK_SIZE: u64 = 70_000_000;
+const UPDATE_SIZE: u64 = 30_000_000;
+
+pub(crate) fn solve(input: &Input) -> Option<Output> {
+    let filesystem = &input.filesystem;
+    let used = filesystem.total_size();
+    let available = DISK_SIZE.checked_sub(used)?;
+    let required = UPDATE_SIZE.saturating_sub(available);
+    if required == 0 {
+        return Some(Output { size: 0 });
+    }
+    filesystem
+        .directory_sizes()
+        .filter(|&size| size >= required)
+        .min()
+        .map(|size| Output { size })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_solve() {
+        struct TestCase {
+            input: Input,
+            output: Option<Output>,
+        }
+        let test_cases = [
+            TestCase {
+                input: include_str!("input.txt").parse().unwrap(),
+                output: Some(Output { size: 24933642 }),
+            },
+            TestCase {
+                input: "$ ls\n40000000 a".parse().unwrap(),
+                output: Some(Output { size: 0 }),
+            },
+            TestCase {
+                input: "$ ls\n70000001 a".parse().unwrap(),
+                output: None,
+            },
+        ];
+        for tc in test_cases {
+            assert_eq!(solve(&tc.input), tc.output);
+        }
+    }
+}
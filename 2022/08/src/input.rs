@@ -0,0 +1,149 @@
+use std::{error::Error, fmt::Display, str::FromStr};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct Forest {
+    pub(crate) width: usize,
+    pub(crate) height: usize,
+    pub(crate) trees: Vec<u8>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) enum ParseForestError {
+    Empty,
+    InvalidCharacter {
+        character: char,
+        position: (usize, usize),
+    },
+    UnevenRow {
+        row: usize,
+        expected: usize,
+        found: usize,
+    },
+}
+
+impl Display for ParseForestError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match *self {
+            Self::Empty => write!(f, "cannot parse forest from empty string"),
+            Self::InvalidCharacter {
+                character,
+                position: (x, y),
+            } => write!(
+                f,
+                "invalid tree height {:?} at column {}, row {}",
+                character, x, y
+            ),
+            Self::UnevenRow {
+                row,
+                expected,
+                found,
+            } => write!(
+                f,
+                "row {} has {} trees but expected {}",
+                row, found, expected
+            ),
+        }
+    }
+}
+
+impl Error for ParseForestError {}
+
+impl FromStr for Forest {
+    type Err = ParseForestError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut trees = Vec::new();
+        let mut width = None;
+        let mut height = 0;
+        for (y, line) in s.lines().enumerate() {
+            let row = line
+                .chars()
+                .enumerate()
+                .map(|(x, character)| {
+                    character.to_digit(10).map(|digit| digit as u8).ok_or(
+                        ParseForestError::InvalidCharacter {
+                            character,
+                            position: (x, y),
+                        },
+                    )
+                })
+                .collect::<Result<Vec<_>, _>>()?;
+            let expected = *width.get_or_insert(row.len());
+            if row.len() != expected {
+                return Err(ParseForestError::UnevenRow {
+                    row: y,
+                    expected,
+                    found: row.len(),
+                });
+            }
+            trees.extend(row);
+            height += 1;
+        }
+        match width {
+            None | Some(0) => Err(ParseForestError::Empty),
+            Some(width) => Ok(Self {
+                width,
+                height,
+                trees,
+            }),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct Input {
+    pub(crate) forest: Forest,
+}
+
+impl FromStr for Input {
+    type Err = ParseForestError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(Self { forest: s.parse()? })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn forest_from_str() {
+        struct TestCase {
+            input: &'static str,
+            output: Result<Forest, ParseForestError>,
+        }
+        let test_cases = [
+            TestCase {
+                input: "303\n255\n",
+                output: Ok(Forest {
+                    width: 3,
+                    height: 2,
+                    trees: vec![3, 0, 3, 2, 5, 5],
+                }),
+            },
+            TestCase {
+                input: "",
+                output: Err(ParseForestError::Empty),
+            },
+            TestCase {
+                input: "12\n3a",
+                output: Err(ParseForestError::InvalidCharacter {
+                    character: 'a',
+                    position: (1, 1),
+                }),
+            },
+            TestCase {
+                input: "123\n45",
+                output: Err(ParseForestError::UnevenRow {
+                    row: 1,
+                    expected: 3,
+                    found: 2,
+                }),
+            },
+        ];
+        for tc in test_cases {
+            assert_eq!(tc.input.parse::<Forest>(), tc.output);
+        }
+    }
+}
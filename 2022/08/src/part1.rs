@@ -0,0 +1,42 @@
+use crate::{input::Input, output::Output, visibility::Visibility};
+
+pub(crate) fn solve(input: &Input) -> Output {
+    let visibility = Visibility::from(&input.forest);
+    Output {
+        value: visibility
+            .visible
+            .iter()
+            .filter(|&&visible| visible)
+            .count() as u64,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_solve() {
+        struct TestCase {
+            input: Input,
+            output: Output,
+        }
+        let test_cases = [
+            TestCase {
+                input: include_str!("input.txt").parse().unwrap(),
+                output: Output { value: 21 },
+            },
+            TestCase {
+                input: "999\n909\n999".parse().unwrap(),
+                output: Output { value: 8 },
+            },
+            TestCase {
+                input: "111\n191\n111".parse().unwrap(),
+                output: Output { value: 9 },
+            },
+        ];
+        for tc in test_cases {
+            assert_eq!(solve(&tc.input), tc.output);
+        }
+    }
+}
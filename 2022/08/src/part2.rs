@@ -0,0 +1,38 @@
+use crate::{input::Input, output::Output, visibility::Visibility};
+
+pub(crate) fn solve(input: &Input) -> Output {
+    let visibility = Visibility::from(&input.forest);
+    Output {
+        value: visibility.scenic_scores.into_iter().max().unwrap_or(0),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_solve() {
+        struct TestCase {
+            input: Input,
+            output: Output,
+        }
+        let test_cases = [
+            TestCase {
+                input: include_str!("input.txt").parse().unwrap(),
+                output: Output { value: 8 },
+            },
+            TestCase {
+                input: "5".parse().unwrap(),
+                output: Output { value: 0 },
+            },
+            TestCase {
+                input: "00000\n00000\n00900\n00000\n00000".parse().unwrap(),
+                output: Output { value: 16 },
+            },
+        ];
+        for tc in test_cases {
+            assert_eq!(solve(&tc.input), tc.output);
+        }
+    }
+}
@@ -0,0 +1,163 @@
+use crate::input::Forest;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct Visibility {
+    pub(crate) visible: Vec<bool>,
+    pub(crate) scenic_scores: Vec<u64>,
+}
+
+impl From<&Forest> for Visibility {
+    fn from(forest: &Forest) -> Self {
+        let (width, height) = (forest.width, forest.height);
+        let mut visibility = Self {
+            visible: vec![false; width * height],
+            scenic_scores: vec![1; width * height],
+        };
+        let mut stack = Vec::new();
+        for y in 0..height {
+            let row = (0..width).map(|x| y * width + x);
+            visibility.sweep(forest, row.clone(), &mut stack);
+            visibility.sweep(forest, row.rev(), &mut stack);
+        }
+        for x in 0..width {
+            let column = (0..height).map(|y| y * width + x);
+            visibility.sweep(forest, column.clone(), &mut stack);
+            visibility.sweep(forest, column.rev(), &mut stack);
+        }
+        visibility
+    }
+}
+
+impl Visibility {
+    fn sweep(
+        &mut self,
+        forest: &Forest,
+        line: impl Iterator<Item = usize>,
+        stack: &mut Vec<(usize, u8)>,
+    ) {
+        stack.clear();
+        for (step, index) in line.enumerate() {
+            let tree = forest.trees[index];
+            while stack.last().is_some_and(|&(_, other)| other < tree) {
+                stack.pop();
+            }
+            let distance = match stack.last() {
+                Some(&(blocker, _)) => step - blocker,
+                None => {
+                    self.visible[index] = true;
+                    step
+                }
+            };
+            self.scenic_scores[index] *= distance as u64;
+            stack.push((step, tree));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn naive(forest: &Forest) -> Visibility {
+        let (width, height) = (forest.width, forest.height);
+        let tree = |x: usize, y: usize| forest.trees[y * width + x];
+        let mut visible = Vec::new();
+        let mut scenic_scores = Vec::new();
+        for y in 0..height {
+            for x in 0..width {
+                let lines: [Vec<u8>; 4] = [
+                    (0..x).rev().map(|x| tree(x, y)).collect(),
+                    (x + 1..width).map(|x| tree(x, y)).collect(),
+                    (0..y).rev().map(|y| tree(x, y)).collect(),
+                    (y + 1..height).map(|y| tree(x, y)).collect(),
+                ];
+                let own = tree(x, y);
+                visible.push(
+                    lines
+                        .iter()
+                        .any(|line| line.iter().all(|&other| other < own)),
+                );
+                scenic_scores.push(
+                    lines
+                        .iter()
+                        .map(|line| {
+                            line.iter()
+                                .position(|&other| other >= own)
+                                .map_or(line.len(), |blocker| blocker + 1)
+                                as u64
+                        })
+                        .product(),
+                );
+            }
+        }
+        Visibility {
+            visible,
+            scenic_scores,
+        }
+    }
+
+    #[test]
+    fn visibility_from_forest() {
+        struct TestCase {
+            input: &'static str,
+            output: Visibility,
+        }
+        let test_cases = [
+            TestCase {
+                input: "30373\n25512\n65332\n33549\n35390",
+                output: Visibility {
+                    visible: vec![
+                        true, true, true, true, true, //
+                        true, true, true, false, true, //
+                        true, true, false, true, true, //
+                        true, false, true, false, true, //
+                        true, true, true, true, true, //
+                    ],
+                    scenic_scores: vec![
+                        0, 0, 0, 0, 0, //
+                        0, 1, 4, 1, 0, //
+                        0, 6, 1, 2, 0, //
+                        0, 1, 8, 3, 0, //
+                        0, 0, 0, 0, 0, //
+                    ],
+                },
+            },
+            TestCase {
+                input: "7",
+                output: Visibility {
+                    visible: vec![true],
+                    scenic_scores: vec![0],
+                },
+            },
+        ];
+        for tc in test_cases {
+            let forest = tc.input.parse::<Forest>().unwrap();
+            assert_eq!(Visibility::from(&forest), tc.output);
+        }
+    }
+
+    #[test]
+    fn visibility_matches_naive() {
+        let mut state = 0x2545f4914f6cdd1d_u64;
+        for _ in 0..200 {
+            state ^= state << 13;
+            state ^= state >> 7;
+            state ^= state << 17;
+            let (width, height) = (state % 9 + 1, (state >> 8) % 9 + 1);
+            let trees = (0..width * height)
+                .map(|_| {
+                    state ^= state << 13;
+                    state ^= state >> 7;
+                    state ^= state << 17;
+                    (state % 10) as u8
+                })
+                .collect();
+            let forest = Forest {
+                width: width as usize,
+                height: height as usize,
+                trees,
+            };
+            assert_eq!(Visibility::from(&forest), naive(&forest));
+        }
+    }
+}
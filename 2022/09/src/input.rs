@@ -0,0 +1,187 @@
+use std::{error::Error, fmt::Display, num::ParseIntError, str::FromStr};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum Direction {
+    Up,
+    Right,
+    Down,
+    Left,
+}
+
+impl Direction {
+    pub(crate) fn offset(self) -> (i32, i32) {
+        match self {
+            Self::Up => (0, 1),
+            Self::Right => (1, 0),
+            Self::Down => (0, -1),
+            Self::Left => (-1, 0),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) struct Motion {
+    pub(crate) direction: Direction,
+    pub(crate) steps: u32,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) enum ParseMotionError {
+    InvalidFormat(String),
+    InvalidDirection(String),
+    ParseSteps(ParseIntError),
+}
+
+impl Display for ParseMotionError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match *self {
+            Self::InvalidFormat(ref motion) => {
+                write!(f, "invalid format for motion: {}", motion)
+            }
+            Self::InvalidDirection(ref direction) => {
+                write!(f, "invalid direction: {}", direction)
+            }
+            Self::ParseSteps(ref err) => {
+                write!(f, "failed to parse steps: {}", err)
+            }
+        }
+    }
+}
+
+impl Error for ParseMotionError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match *self {
+            Self::ParseSteps(ref err) => Some(err),
+            _ => None,
+        }
+    }
+}
+
+impl FromStr for Motion {
+    type Err = ParseMotionError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let Some((direction, steps)) = s.split_once(' ') else {
+            return Err(ParseMotionError::InvalidFormat(s.to_string()));
+        };
+        let direction = match direction {
+            "U" => Direction::Up,
+            "R" => Direction::Right,
+            "D" => Direction::Down,
+            "L" => Direction::Left,
+            _ => {
+                return Err(ParseMotionError::InvalidDirection(
+                    direction.to_string(),
+                ));
+            }
+        };
+        let steps = steps.parse().map_err(ParseMotionError::ParseSteps)?;
+        Ok(Self { direction, steps })
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct ParseInputError {
+    pub(crate) line: usize,
+    pub(crate) source: ParseMotionError,
+}
+
+impl Display for ParseInputError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "failed to parse motion on line {}: {}",
+            self.line, self.source
+        )
+    }
+}
+
+impl Error for ParseInputError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        Some(&self.source)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct Input {
+    pub(crate) motions: Vec<Motion>,
+}
+
+impl FromStr for Input {
+    type Err = ParseInputError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let motions = s
+            .lines()
+            .enumerate()
+            .map(|(index, line)| {
+                line.parse().map_err(|source| ParseInputError {
+                    line: index + 1,
+                    source,
+                })
+            })
+            .collect::<Result<_, _>>()?;
+        Ok(Self { motions })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn input_from_str() {
+        struct TestCase {
+            input: &'static str,
+            output: Result<Input, String>,
+        }
+        let test_cases = [
+            TestCase {
+                input: "R 4\nU 12\nL 0\nD 1",
+                output: Ok(Input {
+                    motions: vec![
+                        Motion {
+                            direction: Direction::Right,
+                            steps: 4,
+                        },
+                        Motion {
+                            direction: Direction::Up,
+                            steps: 12,
+                        },
+                        Motion {
+                            direction: Direction::Left,
+                            steps: 0,
+                        },
+                        Motion {
+                            direction: Direction::Down,
+                            steps: 1,
+                        },
+                    ],
+                }),
+            },
+            TestCase {
+                input: "R 4\nR4",
+                output: Err("failed to parse motion on line 2: invalid \
+                             format for motion: R4"
+                    .to_string()),
+            },
+            TestCase {
+                input: "X 4",
+                output: Err("failed to parse motion on line 1: invalid \
+                             direction: X"
+                    .to_string()),
+            },
+            TestCase {
+                input: "U -1",
+                output: Err("failed to parse motion on line 1: failed to \
+                             parse steps: invalid digit found in string"
+                    .to_string()),
+            },
+        ];
+        for tc in test_cases {
+            let result =
+                tc.input.parse::<Input>().map_err(|err| err.to_string());
+            assert_eq!(result, tc.output);
+        }
+    }
+}
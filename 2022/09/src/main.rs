@@ -0,0 +1,32 @@
+mod input;
+mod output;
+mod part1;
+mod part2;
+mod rope;
+
+use input::Input;
+
+fn main() {
+    let input = include_str!("input.txt")
+        .parse::<Input>()
+        .expect("Input should be valid");
+
+    let args = std::env::args().skip(1).collect::<Vec<_>>();
+    if let Some("render") = args.first().map(String::as_str) {
+        let knots = args.get(1).map_or(2, |knots| {
+            knots
+                .parse()
+                .ok()
+                .filter(|&knots| knots > 0)
+                .expect("Knot count should be a positive integer")
+        });
+        println!("{}", rope::simulate(&input.motions, knots));
+        return;
+    }
+
+    let output = part1::solve(&input);
+    println!("Part 1: {}", output);
+
+    let output = part2::solve(&input);
+    println!("Part 2: {}", output);
+}
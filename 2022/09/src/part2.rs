@@ -0,0 +1,41 @@
+use crate::{input::Input, output::Output, rope::simulate};
+
+const KNOTS: usize = 10;
+
+pub(crate) fn solve(input: &Input) -> Output {
+    Output {
+        positions: simulate(&input.motions, KNOTS).positions.len(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_solve() {
+        struct TestCase {
+            input: Input,
+            output: Output,
+        }
+        let test_cases = [
+            TestCase {
+                input: include_str!("input.txt").parse().unwrap(),
+                output: Output { positions: 1 },
+            },
+            TestCase {
+                input: "R 5\nU 8\nL 8\nD 3\nR 17\nD 10\nL 25\nU 20"
+                    .parse()
+                    .unwrap(),
+                output: Output { positions: 36 },
+            },
+            TestCase {
+                input: "".parse().unwrap(),
+                output: Output { positions: 1 },
+            },
+        ];
+        for tc in test_cases {
+            assert_eq!(solve(&tc.input), tc.output);
+        }
+    }
+}
@@ -0,0 +1,129 @@
+use std::{collections::HashSet, fmt::Display};
+
+use crate::input::{Direction, Motion};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct Rope {
+    knots: Vec<(i32, i32)>,
+}
+
+impl Rope {
+    pub(crate) fn new(knots: usize) -> Self {
+        assert!(knots > 0, "Rope should have at least one knot");
+        Self {
+            knots: vec![(0, 0); knots],
+        }
+    }
+
+    pub(crate) fn tail(&self) -> (i32, i32) {
+        self.knots[self.knots.len() - 1]
+    }
+
+    pub(crate) fn step(&mut self, direction: Direction) {
+        let (dx, dy) = direction.offset();
+        self.knots[0].0 += dx;
+        self.knots[0].1 += dy;
+        for i in 1..self.knots.len() {
+            let (leader, follower) = (self.knots[i - 1], self.knots[i]);
+            let (dx, dy) = (leader.0 - follower.0, leader.1 - follower.1);
+            if dx.abs() <= 1 && dy.abs() <= 1 {
+                break;
+            }
+            self.knots[i] =
+                (follower.0 + dx.signum(), follower.1 + dy.signum());
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct Visited {
+    pub(crate) positions: HashSet<(i32, i32)>,
+}
+
+impl Display for Visited {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let bounds = self.positions.iter().fold(
+            (0, 0, 0, 0),
+            |(min_x, max_x, min_y, max_y), &(x, y)| {
+                (min_x.min(x), max_x.max(x), min_y.min(y), max_y.max(y))
+            },
+        );
+        let (min_x, max_x, min_y, max_y) = bounds;
+        for y in (min_y..=max_y).rev() {
+            for x in min_x..=max_x {
+                let cell = if (x, y) == (0, 0) {
+                    's'
+                } else if self.positions.contains(&(x, y)) {
+                    '#'
+                } else {
+                    '.'
+                };
+                write!(f, "{}", cell)?;
+            }
+            if y != min_y {
+                writeln!(f)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+pub(crate) fn simulate(motions: &[Motion], knots: usize) -> Visited {
+    let mut rope = Rope::new(knots);
+    let mut positions = HashSet::from([rope.tail()]);
+    for motion in motions {
+        for _ in 0..motion.steps {
+            rope.step(motion.direction);
+            positions.insert(rope.tail());
+        }
+    }
+    Visited { positions }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::input::Input;
+
+    #[test]
+    fn rope_step() {
+        struct TestCase {
+            input: (Vec<(i32, i32)>, Direction),
+            output: Vec<(i32, i32)>,
+        }
+        let test_cases = [
+            TestCase {
+                input: (vec![(1, 0), (0, 0)], Direction::Right),
+                output: vec![(2, 0), (1, 0)],
+            },
+            TestCase {
+                input: (vec![(1, 1), (0, 0)], Direction::Up),
+                output: vec![(1, 2), (1, 1)],
+            },
+            TestCase {
+                input: (vec![(1, 1), (0, 0)], Direction::Left),
+                output: vec![(0, 1), (0, 0)],
+            },
+            TestCase {
+                input: (vec![(1, 1), (0, 0), (-1, -1)], Direction::Right),
+                output: vec![(2, 1), (1, 1), (0, 0)],
+            },
+            TestCase {
+                input: (vec![(0, 0)], Direction::Down),
+                output: vec![(0, -1)],
+            },
+        ];
+        for tc in test_cases {
+            let mut rope = Rope { knots: tc.input.0 };
+            rope.step(tc.input.1);
+            assert_eq!(rope.knots, tc.output);
+        }
+    }
+
+    #[test]
+    fn visited_display() {
+        let input = include_str!("input.txt").parse::<Input>().unwrap();
+        let visited = simulate(&input.motions, 2);
+        assert_eq!(visited.to_string(), "..##.\n...##\n.####\n....#\ns###.");
+    }
+}
@@ -0,0 +1,71 @@
+use std::slice;
+
+use crate::input::Instruction;
+
+#[derive(Debug, Clone)]
+pub(crate) struct Cpu<'a> {
+    program: slice::Iter<'a, Instruction>,
+    x: i32,
+    pending: Option<i32>,
+}
+
+impl<'a> Cpu<'a> {
+    pub(crate) fn new(program: &'a [Instruction]) -> Self {
+        Self {
+            program: program.iter(),
+            x: 1,
+            pending: None,
+        }
+    }
+}
+
+impl Iterator for Cpu<'_> {
+    type Item = i32;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let x = self.x;
+        if let Some(value) = self.pending.take() {
+            self.x += value;
+            return Some(x);
+        }
+        match *self.program.next()? {
+            Instruction::Noop => {}
+            Instruction::Addx(value) => self.pending = Some(value),
+        }
+        Some(x)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn cpu_cycles() {
+        struct TestCase {
+            input: Vec<Instruction>,
+            output: Vec<i32>,
+        }
+        let test_cases = [
+            TestCase {
+                input: vec![
+                    Instruction::Noop,
+                    Instruction::Addx(3),
+                    Instruction::Addx(-5),
+                ],
+                output: vec![1, 1, 1, 4, 4],
+            },
+            TestCase {
+                input: vec![Instruction::Addx(2), Instruction::Noop],
+                output: vec![1, 1, 3],
+            },
+            TestCase {
+                input: vec![],
+                output: vec![],
+            },
+        ];
+        for tc in test_cases {
+            assert_eq!(Cpu::new(&tc.input).collect::<Vec<_>>(), tc.output);
+        }
+    }
+}
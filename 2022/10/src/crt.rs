@@ -0,0 +1,71 @@
+use std::fmt::Display;
+
+pub(crate) const WIDTH: usize = 40;
+pub(crate) const HEIGHT: usize = 6;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct Crt {
+    pixels: Vec<bool>,
+}
+
+impl Crt {
+    pub(crate) fn is_lit(&self, x: usize, y: usize) -> bool {
+        self.pixels[y * WIDTH + x]
+    }
+}
+
+impl FromIterator<i32> for Crt {
+    fn from_iter<T: IntoIterator<Item = i32>>(iter: T) -> Self {
+        let mut pixels = vec![false; WIDTH * HEIGHT];
+        for (cycle, sprite) in iter.into_iter().take(WIDTH * HEIGHT).enumerate()
+        {
+            let column = (cycle % WIDTH) as i32;
+            pixels[cycle] = (sprite - column).abs() <= 1;
+        }
+        Self { pixels }
+    }
+}
+
+impl Display for Crt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for (y, row) in self.pixels.chunks(WIDTH).enumerate() {
+            if y > 0 {
+                writeln!(f)?;
+            }
+            for &lit in row {
+                write!(f, "{}", if lit { '#' } else { '.' })?;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn crt_from_iter() {
+        struct TestCase {
+            input: Vec<i32>,
+            output: &'static str,
+        }
+        let test_cases = [
+            TestCase {
+                input: vec![1, 1, 16, 16, 5, 5, 11, 11, 8, 8],
+                output: "##..##..##..............................",
+            },
+            TestCase {
+                input: (0..WIDTH as i32).chain([-2, 41]).collect(),
+                output: "########################################\n\
+                         ........................................",
+            },
+        ];
+        for tc in test_cases {
+            let crt = tc.input.into_iter().collect::<Crt>();
+            let rows = crt.to_string();
+            let rows = rows.lines().take(tc.output.lines().count());
+            assert_eq!(rows.collect::<Vec<_>>().join("\n"), tc.output);
+        }
+    }
+}
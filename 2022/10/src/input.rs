@@ -0,0 +1,144 @@
+use std::{error::Error, fmt::Display, num::ParseIntError, str::FromStr};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum Instruction {
+    Noop,
+    Addx(i32),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) enum ParseInstructionError {
+    UnknownInstruction(String),
+    ParseOperand(ParseIntError),
+}
+
+impl Display for ParseInstructionError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match *self {
+            Self::UnknownInstruction(ref instruction) => {
+                write!(f, "unknown instruction: {}", instruction)
+            }
+            Self::ParseOperand(ref err) => {
+                write!(f, "failed to parse operand: {}", err)
+            }
+        }
+    }
+}
+
+impl Error for ParseInstructionError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match *self {
+            Self::UnknownInstruction(_) => None,
+            Self::ParseOperand(ref err) => Some(err),
+        }
+    }
+}
+
+impl FromStr for Instruction {
+    type Err = ParseInstructionError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.split(' ').collect::<Vec<_>>()[..] {
+            ["noop"] => Ok(Self::Noop),
+            ["addx", operand] => Ok(Self::Addx(
+                operand
+                    .parse()
+                    .map_err(ParseInstructionError::ParseOperand)?,
+            )),
+            _ => Err(ParseInstructionError::UnknownInstruction(s.to_string())),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct ParseInputError {
+    pub(crate) line: usize,
+    pub(crate) source: ParseInstructionError,
+}
+
+impl Display for ParseInputError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "failed to parse instruction on line {}: {}",
+            self.line, self.source
+        )
+    }
+}
+
+impl Error for ParseInputError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        Some(&self.source)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct Input {
+    pub(crate) program: Vec<Instruction>,
+}
+
+impl FromStr for Input {
+    type Err = ParseInputError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let program = s
+            .lines()
+            .enumerate()
+            .map(|(index, line)| {
+                line.parse().map_err(|source| ParseInputError {
+                    line: index + 1,
+                    source,
+                })
+            })
+            .collect::<Result<_, _>>()?;
+        Ok(Self { program })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn input_from_str() {
+        struct TestCase {
+            input: &'static str,
+            output: Result<Input, String>,
+        }
+        let test_cases = [
+            TestCase {
+                input: "noop\naddx 3\naddx -5",
+                output: Ok(Input {
+                    program: vec![
+                        Instruction::Noop,
+                        Instruction::Addx(3),
+                        Instruction::Addx(-5),
+                    ],
+                }),
+            },
+            TestCase {
+                input: "noop\naddy 3",
+                output: Err("failed to parse instruction on line 2: unknown \
+                             instruction: addy 3"
+                    .to_string()),
+            },
+            TestCase {
+                input: "addx",
+                output: Err("failed to parse instruction on line 1: unknown \
+                             instruction: addx"
+                    .to_string()),
+            },
+            TestCase {
+                input: "addx x",
+                output: Err("failed to parse instruction on line 1: failed \
+                             to parse operand: invalid digit found in string"
+                    .to_string()),
+            },
+        ];
+        for tc in test_cases {
+            let result =
+                tc.input.parse::<Input>().map_err(|err| err.to_string());
+            assert_eq!(result, tc.output);
+        }
+    }
+}
@@ -0,0 +1,101 @@
+use crate::crt::{Crt, HEIGHT, WIDTH};
+
+const GLYPH_WIDTH: usize = 4;
+const GLYPH_SPACING: usize = 1;
+
+const GLYPHS: [(char, [&str; HEIGHT]); 16] = [
+    ('A', [".##.", "#..#", "#..#", "####", "#..#", "#..#"]),
+    ('B', ["###.", "#..#", "###.", "#..#", "#..#", "###."]),
+    ('C', [".##.", "#..#", "#...", "#...", "#..#", ".##."]),
+    ('E', ["####", "#...", "###.", "#...", "#...", "####"]),
+    ('F', ["####", "#...", "###.", "#...", "#...", "#..."]),
+    ('G', [".##.", "#..#", "#...", "#.##", "#..#", ".###"]),
+    ('H', ["#..#", "#..#", "####", "#..#", "#..#", "#..#"]),
+    ('J', ["..##", "...#", "...#", "...#", "#..#", ".##."]),
+    ('K', ["#..#", "#.#.", "##..", "#.#.", "#.#.", "#..#"]),
+    ('L', ["#...", "#...", "#...", "#...", "#...", "####"]),
+    ('O', [".##.", "#..#", "#..#", "#..#", "#..#", ".##."]),
+    ('P', ["###.", "#..#", "#..#", "###.", "#...", "#..."]),
+    ('R', ["###.", "#..#", "#..#", "###.", "#.#.", "#..#"]),
+    ('S', [".###", "#...", "#...", ".##.", "...#", "###."]),
+    ('U', ["#..#", "#..#", "#..#", "#..#", "#..#", ".##."]),
+    ('Z', ["####", "...#", "..#.", ".#..", "#...", "####"]),
+];
+
+fn recognize_glyph(crt: &Crt, left: usize) -> Option<char> {
+    GLYPHS.iter().find_map(|&(letter, rows)| {
+        rows.iter()
+            .enumerate()
+            .all(|(y, row)| {
+                row.chars().enumerate().all(|(dx, pixel)| {
+                    crt.is_lit(left + dx, y) == (pixel == '#')
+                })
+            })
+            .then_some(letter)
+    })
+}
+
+pub(crate) fn recognize(crt: &Crt) -> Option<String> {
+    let letters = (WIDTH + GLYPH_SPACING) / (GLYPH_WIDTH + GLYPH_SPACING);
+    (0..letters)
+        .map(|i| {
+            let left = i * (GLYPH_WIDTH + GLYPH_SPACING);
+            let gap = left + GLYPH_WIDTH;
+            let spaced =
+                gap >= WIDTH || (0..HEIGHT).all(|y| !crt.is_lit(gap, y));
+            spaced.then(|| recognize_glyph(crt, left)).flatten()
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn render(word: &str) -> Crt {
+        let mut rows = vec![String::new(); HEIGHT];
+        for letter in word.chars() {
+            let (_, glyph) =
+                GLYPHS.iter().find(|&&(glyph, _)| glyph == letter).unwrap();
+            for (row, glyph_row) in rows.iter_mut().zip(glyph) {
+                row.push_str(glyph_row);
+                row.push('.');
+            }
+        }
+        let picture = rows.join("");
+        let sprites = picture.chars().enumerate().map(|(cycle, pixel)| {
+            let column = (cycle % WIDTH) as i32;
+            if pixel == '#' { column } else { column - 3 }
+        });
+        sprites.collect()
+    }
+
+    #[test]
+    fn recognize_letters() {
+        struct TestCase {
+            input: Crt,
+            output: Option<String>,
+        }
+        let test_cases = [
+            TestCase {
+                input: render("ABCEFGHJ"),
+                output: Some("ABCEFGHJ".to_string()),
+            },
+            TestCase {
+                input: render("KLOPRSUZ"),
+                output: Some("KLOPRSUZ".to_string()),
+            },
+            TestCase {
+                input: (0..240).map(|cycle| cycle % 40).collect(),
+                output: None,
+            },
+            TestCase {
+                input: [].into_iter().collect(),
+                output: None,
+            },
+        ];
+        for tc in test_cases {
+            assert_eq!(recognize(&tc.input), tc.output);
+        }
+    }
+}
@@ -0,0 +1,20 @@
+use std::fmt::Display;
+
+use crate::crt::Crt;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) enum Output {
+    SignalStrength(i32),
+    Letters(String),
+    Screen(Crt),
+}
+
+impl Display for Output {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match *self {
+            Self::SignalStrength(strength) => write!(f, "{}", strength),
+            Self::Letters(ref letters) => write!(f, "{}", letters),
+            Self::Screen(ref crt) => write!(f, "\n{}", crt),
+        }
+    }
+}
@@ -0,0 +1,56 @@
+use crate::{cpu::Cpu, input::Input, output::Output};
+
+const FIRST_CYCLE: usize = 20;
+const CYCLE_INTERVAL: usize = 40;
+
+pub(crate) fn solve(input: &Input) -> Output {
+    Output::SignalStrength(
+        Cpu::new(&input.program)
+            .enumerate()
+            .map(|(index, x)| (index + 1, x))
+            .filter(|&(cycle, _)| {
+                cycle >= FIRST_CYCLE
+                    && (cycle - FIRST_CYCLE).is_multiple_of(CYCLE_INTERVAL)
+            })
+            .map(|(cycle, x)| cycle as i32 * x)
+            .sum(),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_solve() {
+        struct TestCase {
+            input: Input,
+            output: Output,
+        }
+        let test_cases = [
+            TestCase {
+                input: include_str!("input.txt").parse().unwrap(),
+                output: Output::SignalStrength(17040),
+            },
+            TestCase {
+                input: "noop\naddx 3\naddx -5".parse().unwrap(),
+                output: Output::SignalStrength(0),
+            },
+            TestCase {
+                input: format!("addx 4\n{}", "noop\n".repeat(60))
+                    .parse()
+                    .unwrap(),
+                output: Output::SignalStrength(20 * 5 + 60 * 5),
+            },
+            TestCase {
+                input: format!("{}addx -10\n{}", "noop\n".repeat(18), "noop\n")
+                    .parse()
+                    .unwrap(),
+                output: Output::SignalStrength(20),
+            },
+        ];
+        for tc in test_cases {
+            assert_eq!(solve(&tc.input), tc.output);
+        }
+    }
+}
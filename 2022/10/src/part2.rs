@@ -0,0 +1,39 @@
+use crate::{cpu::Cpu, crt::Crt, input::Input, ocr, output::Output};
+
+pub(crate) fn solve(input: &Input) -> Output {
+    let crt = Cpu::new(&input.program).collect::<Crt>();
+    match ocr::recognize(&crt) {
+        Some(letters) => Output::Letters(letters),
+        None => Output::Screen(crt),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_solve() {
+        struct TestCase {
+            input: Input,
+            output: String,
+        }
+        let test_cases = [
+            TestCase {
+                input: include_str!("input.txt").parse().unwrap(),
+                output: "PZULBFRJ".to_string(),
+            },
+            TestCase {
+                input: "addx 5\nnoop\nnoop\nnoop\nnoop".parse().unwrap(),
+                output: format!(
+                    "\n##...#{}{}",
+                    ".".repeat(34),
+                    format!("\n{}", ".".repeat(40)).repeat(5)
+                ),
+            },
+        ];
+        for tc in test_cases {
+            assert_eq!(solve(&tc.input).to_string(), tc.output);
+        }
+    }
+}
@@ -0,0 +1,493 @@
+use std::{error::Error, fmt::Display, num::ParseIntError, str::FromStr};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum Operand {
+    Old,
+    Value(u64),
+}
+
+impl Operand {
+    fn resolve(self, old: u64) -> u64 {
+        match self {
+            Self::Old => old,
+            Self::Value(value) => value,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum Operation {
+    Add(Operand),
+    Multiply(Operand),
+}
+
+impl Operation {
+    pub(crate) fn apply(self, old: u64) -> u64 {
+        match self {
+            Self::Add(operand) => old + operand.resolve(old),
+            Self::Multiply(operand) => old * operand.resolve(old),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct Monkey {
+    pub(crate) id: usize,
+    pub(crate) items: Vec<u64>,
+    pub(crate) operation: Operation,
+    pub(crate) divisor: u64,
+    pub(crate) if_true: usize,
+    pub(crate) if_false: usize,
+}
+
+impl Monkey {
+    pub(crate) fn target(&self, worry: u64) -> usize {
+        if worry.is_multiple_of(self.divisor) {
+            self.if_true
+        } else {
+            self.if_false
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) enum ParseMonkeyError {
+    MissingLine {
+        expected: &'static str,
+    },
+    InvalidLine {
+        expected: &'static str,
+        line: String,
+    },
+    ParseNumber {
+        field: &'static str,
+        source: ParseIntError,
+    },
+    InvalidOperation(String),
+    ZeroDivisor,
+    UnexpectedLine(String),
+}
+
+impl Display for ParseMonkeyError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match *self {
+            Self::MissingLine { expected } => {
+                write!(f, "missing line starting with {:?}", expected)
+            }
+            Self::InvalidLine { expected, ref line } => {
+                write!(
+                    f,
+                    "expected line starting with {:?}: {}",
+                    expected, line
+                )
+            }
+            Self::ParseNumber { field, ref source } => {
+                write!(f, "failed to parse {}: {}", field, source)
+            }
+            Self::InvalidOperation(ref operation) => {
+                write!(f, "invalid operation: {}", operation)
+            }
+            Self::ZeroDivisor => write!(f, "test cannot be divisible by 0"),
+            Self::UnexpectedLine(ref line) => {
+                write!(f, "unexpected line: {}", line)
+            }
+        }
+    }
+}
+
+impl Error for ParseMonkeyError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match *self {
+            Self::ParseNumber { ref source, .. } => Some(source),
+            _ => None,
+        }
+    }
+}
+
+fn parse_number<T: FromStr<Err = ParseIntError>>(
+    field: &'static str,
+    s: &str,
+) -> Result<T, ParseMonkeyError> {
+    s.trim()
+        .parse()
+        .map_err(|source| ParseMonkeyError::ParseNumber { field, source })
+}
+
+impl FromStr for Operation {
+    type Err = ParseMonkeyError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let invalid = || ParseMonkeyError::InvalidOperation(s.to_string());
+        let ["new", "=", "old", operator, operand] =
+            s.split_whitespace().collect::<Vec<_>>()[..]
+        else {
+            return Err(invalid());
+        };
+        let operand = match operand {
+            "old" => Operand::Old,
+            value => Operand::Value(parse_number("operand", value)?),
+        };
+        match operator {
+            "+" => Ok(Self::Add(operand)),
+            "*" => Ok(Self::Multiply(operand)),
+            _ => Err(invalid()),
+        }
+    }
+}
+
+impl FromStr for Monkey {
+    type Err = ParseMonkeyError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut lines = s.lines().map(str::trim);
+        let mut field = |prefix: &'static str| {
+            let line = lines
+                .next()
+                .ok_or(ParseMonkeyError::MissingLine { expected: prefix })?;
+            line.strip_prefix(prefix).map(str::trim).ok_or_else(|| {
+                ParseMonkeyError::InvalidLine {
+                    expected: prefix,
+                    line: line.to_string(),
+                }
+            })
+        };
+
+        let id = field("Monkey ")?;
+        let id = parse_number("monkey id", id.trim_end_matches(':'))?;
+        let items = field("Starting items:")?;
+        let items = if items.is_empty() {
+            Vec::new()
+        } else {
+            items
+                .split(',')
+                .map(|item| parse_number("item", item))
+                .collect::<Result<_, _>>()?
+        };
+        let operation = field("Operation:")?.parse()?;
+        let divisor = parse_number("divisor", field("Test: divisible by")?)?;
+        if divisor == 0 {
+            return Err(ParseMonkeyError::ZeroDivisor);
+        }
+        let if_true =
+            parse_number("target", field("If true: throw to monkey")?)?;
+        let if_false =
+            parse_number("target", field("If false: throw to monkey")?)?;
+        if let Some(line) = lines.find(|line| !line.is_empty()) {
+            return Err(ParseMonkeyError::UnexpectedLine(line.to_string()));
+        }
+        Ok(Self {
+            id,
+            items,
+            operation,
+            divisor,
+            if_true,
+            if_false,
+        })
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) enum ParseInputError {
+    Monkey {
+        index: usize,
+        source: ParseMonkeyError,
+    },
+    UnexpectedId {
+        expected: usize,
+        found: usize,
+    },
+    InvalidTarget {
+        monkey: usize,
+        target: usize,
+    },
+    ModulusOverflow,
+}
+
+impl Display for ParseInputError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match *self {
+            Self::Monkey { index, ref source } => {
+                write!(f, "failed to parse monkey {}: {}", index, source)
+            }
+            Self::UnexpectedId { expected, found } => {
+                write!(f, "expected monkey {} but found {}", expected, found)
+            }
+            Self::InvalidTarget { monkey, target } => write!(
+                f,
+                "monkey {} throws to invalid monkey {}",
+                monkey, target
+            ),
+            Self::ModulusOverflow => write!(
+                f,
+                "least common multiple of divisors does not fit in 64 bits"
+            ),
+        }
+    }
+}
+
+impl Error for ParseInputError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match *self {
+            Self::Monkey { ref source, .. } => Some(source),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct Input {
+    pub(crate) monkeys: Vec<Monkey>,
+    pub(crate) modulus: u64,
+}
+
+fn gcd(a: u64, b: u64) -> u64 {
+    if b == 0 { a } else { gcd(b, a % b) }
+}
+
+impl FromStr for Input {
+    type Err = ParseInputError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let monkeys = s
+            .trim()
+            .split("\n\n")
+            .enumerate()
+            .map(|(index, monkey)| {
+                let monkey = monkey.parse::<Monkey>().map_err(|source| {
+                    ParseInputError::Monkey { index, source }
+                })?;
+                if monkey.id != index {
+                    return Err(ParseInputError::UnexpectedId {
+                        expected: index,
+                        found: monkey.id,
+                    });
+                }
+                Ok(monkey)
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        for monkey in &monkeys {
+            for target in [monkey.if_true, monkey.if_false] {
+                if target >= monkeys.len() || target == monkey.id {
+                    return Err(ParseInputError::InvalidTarget {
+                        monkey: monkey.id,
+                        target,
+                    });
+                }
+            }
+        }
+        let modulus = monkeys
+            .iter()
+            .try_fold(1u64, |lcm, monkey| {
+                (lcm / gcd(lcm, monkey.divisor)).checked_mul(monkey.divisor)
+            })
+            .ok_or(ParseInputError::ModulusOverflow)?;
+        Ok(Self { monkeys, modulus })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn monkey_from_str() {
+        struct TestCase {
+            input: &'static str,
+            output: Result<Monkey, ParseMonkeyError>,
+        }
+        let test_cases = [
+            TestCase {
+                input: "Monkey 2:
+  Starting items: 79, 60, 97
+  Operation: new = old * old
+  Test: divisible by 13
+    If true: throw to monkey 1
+    If false: throw to monkey 3",
+                output: Ok(Monkey {
+                    id: 2,
+                    items: vec![79, 60, 97],
+                    operation: Operation::Multiply(Operand::Old),
+                    divisor: 13,
+                    if_true: 1,
+                    if_false: 3,
+                }),
+            },
+            TestCase {
+                input: "Monkey 0:
+  Starting items:
+  Operation: new = old + 6
+  Test: divisible by 19
+    If true: throw to monkey 2
+    If false: throw to monkey 0",
+                output: Ok(Monkey {
+                    id: 0,
+                    items: vec![],
+                    operation: Operation::Add(Operand::Value(6)),
+                    divisor: 19,
+                    if_true: 2,
+                    if_false: 0,
+                }),
+            },
+            TestCase {
+                input: "Monkey 0:
+  Starting items: 79, 98
+  Operation: new = old * 19",
+                output: Err(ParseMonkeyError::MissingLine {
+                    expected: "Test: divisible by",
+                }),
+            },
+            TestCase {
+                input: "Monkey 0:
+  Items: 79, 98",
+                output: Err(ParseMonkeyError::InvalidLine {
+                    expected: "Starting items:",
+                    line: "Items: 79, 98".to_string(),
+                }),
+            },
+            TestCase {
+                input: "Monkey 0:
+  Starting items: 79, 98
+  Operation: new = old - 19",
+                output: Err(ParseMonkeyError::InvalidOperation(
+                    "new = old - 19".to_string(),
+                )),
+            },
+            TestCase {
+                input: "Monkey 0:
+  Starting items: 79, 98
+  Operation: new = old * 19
+  Test: divisible by 0",
+                output: Err(ParseMonkeyError::ZeroDivisor),
+            },
+            TestCase {
+                input: "Monkey 0:
+  Starting items: 79, 98
+  Operation: new = old * 19
+  Test: divisible by 23
+    If true: throw to monkey 2
+    If false: throw to monkey 3
+    Otherwise: panic",
+                output: Err(ParseMonkeyError::UnexpectedLine(
+                    "Otherwise: panic".to_string(),
+                )),
+            },
+        ];
+        for tc in test_cases {
+            assert_eq!(tc.input.parse::<Monkey>(), tc.output);
+        }
+    }
+
+    #[test]
+    fn monkey_from_str_number_error() {
+        struct TestCase {
+            input: &'static str,
+            output: &'static str,
+        }
+        let test_cases = [
+            TestCase {
+                input: "Monkey x:",
+                output: "failed to parse monkey id: invalid digit found in \
+                         string",
+            },
+            TestCase {
+                input: "Monkey 0:\n  Starting items: 79,, 98",
+                output: "failed to parse item: cannot parse integer from \
+                         empty string",
+            },
+            TestCase {
+                input: "Monkey 0:
+  Starting items: 79
+  Operation: new = old * x",
+                output: "failed to parse operand: invalid digit found in \
+                         string",
+            },
+        ];
+        for tc in test_cases {
+            let err = tc.input.parse::<Monkey>().unwrap_err();
+            assert_eq!(err.to_string(), tc.output);
+        }
+    }
+
+    #[test]
+    fn input_from_str_error() {
+        struct TestCase {
+            input: String,
+            output: ParseInputError,
+        }
+        let example = include_str!("input.txt");
+        let test_cases = [
+            TestCase {
+                input: example.replace("Monkey 2:", "Monkey 5:"),
+                output: ParseInputError::UnexpectedId {
+                    expected: 2,
+                    found: 5,
+                },
+            },
+            TestCase {
+                input: example
+                    .replace("throw to monkey 3", "throw to monkey 4"),
+                output: ParseInputError::InvalidTarget {
+                    monkey: 0,
+                    target: 4,
+                },
+            },
+            TestCase {
+                input: example.replace(
+                    "If false: throw to monkey 0",
+                    "If false: throw to monkey 1",
+                ),
+                output: ParseInputError::InvalidTarget {
+                    monkey: 1,
+                    target: 1,
+                },
+            },
+            TestCase {
+                input: example
+                    .replace("divisible by 23", "divisible by 4294967296")
+                    .replace("divisible by 19", "divisible by 4294967297"),
+                output: ParseInputError::ModulusOverflow,
+            },
+            TestCase {
+                input: example.replace("old + 3", "old / 3"),
+                output: ParseInputError::Monkey {
+                    index: 3,
+                    source: ParseMonkeyError::InvalidOperation(
+                        "new = old / 3".to_string(),
+                    ),
+                },
+            },
+        ];
+        for tc in test_cases {
+            assert_eq!(tc.input.parse::<Input>(), Err(tc.output));
+        }
+    }
+
+    #[test]
+    fn input_modulus() {
+        struct TestCase {
+            input: String,
+            output: u64,
+        }
+        let example = include_str!("input.txt");
+        let test_cases = [
+            TestCase {
+                input: example.to_string(),
+                output: 96577,
+            },
+            TestCase {
+                input: example.replace("divisible by 19", "divisible by 46"),
+                output: 10166,
+            },
+            TestCase {
+                input: example
+                    .replace("divisible by 23", "divisible by 4294967296")
+                    .replace("divisible by 19", "divisible by 2"),
+                output: 949187772416,
+            },
+        ];
+        for tc in test_cases {
+            let input = tc.input.parse::<Input>().unwrap();
+            assert_eq!(input.modulus, tc.output);
+        }
+    }
+}
@@ -0,0 +1,38 @@
+use crate::{
+    input::Input,
+    output::Output,
+    simulation::{Relief, inspections, monkey_business},
+};
+
+const ROUNDS: usize = 20;
+const RELIEF_DIVISOR: u64 = 3;
+
+pub(crate) fn solve(input: &Input) -> Output {
+    let inspections =
+        inspections(&input.monkeys, ROUNDS, Relief::DivideBy(RELIEF_DIVISOR));
+    Output {
+        monkey_business: monkey_business(&inspections),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_solve() {
+        struct TestCase {
+            input: Input,
+            output: Output,
+        }
+        let test_cases = [TestCase {
+            input: include_str!("input.txt").parse().unwrap(),
+            output: Output {
+                monkey_business: 10605,
+            },
+        }];
+        for tc in test_cases {
+            assert_eq!(solve(&tc.input), tc.output);
+        }
+    }
+}
@@ -0,0 +1,37 @@
+use crate::{
+    input::Input,
+    output::Output,
+    simulation::{Relief, inspections, monkey_business},
+};
+
+const ROUNDS: usize = 10_000;
+
+pub(crate) fn solve(input: &Input) -> Output {
+    let inspections =
+        inspections(&input.monkeys, ROUNDS, Relief::Modulo(input.modulus));
+    Output {
+        monkey_business: monkey_business(&inspections),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_solve() {
+        struct TestCase {
+            input: Input,
+            output: Output,
+        }
+        let test_cases = [TestCase {
+            input: include_str!("input.txt").parse().unwrap(),
+            output: Output {
+                monkey_business: 2713310158,
+            },
+        }];
+        for tc in test_cases {
+            assert_eq!(solve(&tc.input), tc.output);
+        }
+    }
+}
@@ -0,0 +1,82 @@
+use crate::input::Monkey;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum Relief {
+    DivideBy(u64),
+    Modulo(u64),
+}
+
+impl Relief {
+    fn apply(self, worry: u64) -> u64 {
+        match self {
+            Self::DivideBy(divisor) => worry / divisor,
+            Self::Modulo(modulus) => worry % modulus,
+        }
+    }
+}
+
+pub(crate) fn inspections(
+    monkeys: &[Monkey],
+    rounds: usize,
+    relief: Relief,
+) -> Vec<u64> {
+    let mut items = monkeys
+        .iter()
+        .map(|monkey| monkey.items.clone())
+        .collect::<Vec<_>>();
+    let mut inspections = vec![0; monkeys.len()];
+    for _ in 0..rounds {
+        for (index, monkey) in monkeys.iter().enumerate() {
+            let held = std::mem::take(&mut items[index]);
+            inspections[index] += held.len() as u64;
+            for worry in held {
+                let worry = relief.apply(monkey.operation.apply(worry));
+                items[monkey.target(worry)].push(worry);
+            }
+        }
+    }
+    inspections
+}
+
+pub(crate) fn monkey_business(inspections: &[u64]) -> u64 {
+    let mut inspections = inspections.to_vec();
+    inspections.sort_unstable_by(|a, b| b.cmp(a));
+    inspections.iter().take(2).product()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::input::Input;
+
+    #[test]
+    fn test_inspections() {
+        struct TestCase {
+            input: (usize, Relief),
+            output: Vec<u64>,
+        }
+        let test_cases = [
+            TestCase {
+                input: (20, Relief::DivideBy(3)),
+                output: vec![101, 95, 7, 105],
+            },
+            TestCase {
+                input: (1, Relief::Modulo(23 * 19 * 13 * 17)),
+                output: vec![2, 4, 3, 6],
+            },
+            TestCase {
+                input: (20, Relief::Modulo(23 * 19 * 13 * 17)),
+                output: vec![99, 97, 8, 103],
+            },
+            TestCase {
+                input: (1000, Relief::Modulo(23 * 19 * 13 * 17)),
+                output: vec![5204, 4792, 199, 5192],
+            },
+        ];
+        let input = include_str!("input.txt").parse::<Input>().unwrap();
+        for tc in test_cases {
+            let result = inspections(&input.monkeys, tc.input.0, tc.input.1);
+            assert_eq!(result, tc.output);
+        }
+    }
+}
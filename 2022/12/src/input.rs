@@ -0,0 +1,246 @@
+use std::{error::Error, fmt::Display, str::FromStr};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct Heightmap {
+    pub(crate) width: usize,
+    pub(crate) height: usize,
+    pub(crate) elevations: Vec<u8>,
+    pub(crate) start: (usize, usize),
+    pub(crate) end: (usize, usize),
+}
+
+impl Heightmap {
+    pub(crate) fn elevation(&self, (x, y): (usize, usize)) -> u8 {
+        self.elevations[y * self.width + x]
+    }
+
+    pub(crate) fn neighbours(
+        &self,
+        (x, y): (usize, usize),
+    ) -> impl Iterator<Item = (usize, usize)> {
+        let (width, height) = (self.width, self.height);
+        [
+            x.checked_sub(1).map(|x| (x, y)),
+            (x + 1 < width).then_some((x + 1, y)),
+            y.checked_sub(1).map(|y| (x, y)),
+            (y + 1 < height).then_some((x, y + 1)),
+        ]
+        .into_iter()
+        .flatten()
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) enum ParseHeightmapError {
+    Empty,
+    InvalidCharacter {
+        character: char,
+        position: (usize, usize),
+    },
+    UnevenRow {
+        row: usize,
+        expected: usize,
+        found: usize,
+    },
+    MissingStart,
+    MultipleStarts {
+        positions: Vec<(usize, usize)>,
+    },
+    MissingEnd,
+    MultipleEnds {
+        positions: Vec<(usize, usize)>,
+    },
+}
+
+impl Display for ParseHeightmapError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match *self {
+            Self::Empty => {
+                write!(f, "cannot parse heightmap from empty string")
+            }
+            Self::InvalidCharacter {
+                character,
+                position: (x, y),
+            } => write!(
+                f,
+                "invalid elevation {:?} at column {}, row {}",
+                character, x, y
+            ),
+            Self::UnevenRow {
+                row,
+                expected,
+                found,
+            } => write!(
+                f,
+                "row {} has {} squares but expected {}",
+                row, found, expected
+            ),
+            Self::MissingStart => write!(f, "heightmap has no start"),
+            Self::MultipleStarts { ref positions } => {
+                write!(f, "heightmap has multiple starts at {:?}", positions)
+            }
+            Self::MissingEnd => write!(f, "heightmap has no end"),
+            Self::MultipleEnds { ref positions } => {
+                write!(f, "heightmap has multiple ends at {:?}", positions)
+            }
+        }
+    }
+}
+
+impl Error for ParseHeightmapError {}
+
+impl FromStr for Heightmap {
+    type Err = ParseHeightmapError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut elevations = Vec::new();
+        let mut starts = Vec::new();
+        let mut ends = Vec::new();
+        let mut width = None;
+        let mut height = 0;
+        for (y, line) in s.lines().enumerate() {
+            let mut row = 0;
+            for (x, character) in line.chars().enumerate() {
+                let elevation = match character {
+                    'S' => {
+                        starts.push((x, y));
+                        'a'
+                    }
+                    'E' => {
+                        ends.push((x, y));
+                        'z'
+                    }
+                    'a'..='z' => character,
+                    _ => {
+                        return Err(ParseHeightmapError::InvalidCharacter {
+                            character,
+                            position: (x, y),
+                        });
+                    }
+                };
+                elevations.push(elevation as u8 - b'a');
+                row += 1;
+            }
+            let expected = *width.get_or_insert(row);
+            if row != expected {
+                return Err(ParseHeightmapError::UnevenRow {
+                    row: y,
+                    expected,
+                    found: row,
+                });
+            }
+            height += 1;
+        }
+        let width = match width {
+            None | Some(0) => return Err(ParseHeightmapError::Empty),
+            Some(width) => width,
+        };
+        let start = match starts[..] {
+            [] => return Err(ParseHeightmapError::MissingStart),
+            [start] => start,
+            _ => {
+                return Err(ParseHeightmapError::MultipleStarts {
+                    positions: starts,
+                });
+            }
+        };
+        let end = match ends[..] {
+            [] => return Err(ParseHeightmapError::MissingEnd),
+            [end] => end,
+            _ => {
+                return Err(ParseHeightmapError::MultipleEnds {
+                    positions: ends,
+                });
+            }
+        };
+        Ok(Self {
+            width,
+            height,
+            elevations,
+            start,
+            end,
+        })
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct Input {
+    pub(crate) heightmap: Heightmap,
+}
+
+impl FromStr for Input {
+    type Err = ParseHeightmapError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(Self {
+            heightmap: s.parse()?,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn heightmap_from_str() {
+        struct TestCase {
+            input: &'static str,
+            output: Result<Heightmap, ParseHeightmapError>,
+        }
+        let test_cases = [
+            TestCase {
+                input: "Sbc\nzyE",
+                output: Ok(Heightmap {
+                    width: 3,
+                    height: 2,
+                    elevations: vec![0, 1, 2, 25, 24, 25],
+                    start: (0, 0),
+                    end: (2, 1),
+                }),
+            },
+            TestCase {
+                input: "",
+                output: Err(ParseHeightmapError::Empty),
+            },
+            TestCase {
+                input: "SbE\na1c",
+                output: Err(ParseHeightmapError::InvalidCharacter {
+                    character: '1',
+                    position: (1, 1),
+                }),
+            },
+            TestCase {
+                input: "SbE\nac",
+                output: Err(ParseHeightmapError::UnevenRow {
+                    row: 1,
+                    expected: 3,
+                    found: 2,
+                }),
+            },
+            TestCase {
+                input: "abE",
+                output: Err(ParseHeightmapError::MissingStart),
+            },
+            TestCase {
+                input: "SbE\nSbc",
+                output: Err(ParseHeightmapError::MultipleStarts {
+                    positions: vec![(0, 0), (0, 1)],
+                }),
+            },
+            TestCase {
+                input: "Sbc",
+                output: Err(ParseHeightmapError::MissingEnd),
+            },
+            TestCase {
+                input: "SEE",
+                output: Err(ParseHeightmapError::MultipleEnds {
+                    positions: vec![(1, 0), (2, 0)],
+                }),
+            },
+        ];
+        for tc in test_cases {
+            assert_eq!(tc.input.parse::<Heightmap>(), tc.output);
+        }
+    }
+}
@@ -0,0 +1,48 @@
+mod input;
+mod output;
+mod overlay;
+mod part1;
+mod part2;
+mod search;
+
+use input::Input;
+use overlay::Overlay;
+use search::{Descent, lowest_squares};
+
+fn main() {
+    let input = include_str!("input.txt")
+        .parse::<Input>()
+        .expect("Input should be valid");
+
+    let args = std::env::args().skip(1).collect::<Vec<_>>();
+    if let Some("path") = args.first().map(String::as_str) {
+        let heightmap = &input.heightmap;
+        let descent = Descent::from(heightmap);
+        let start = if args.iter().any(|arg| arg == "--any-start") {
+            descent.nearest(lowest_squares(heightmap))
+        } else {
+            Some(heightmap.start)
+        };
+        match start
+            .ok_or(search::NoPathError)
+            .and_then(|start| descent.path(start))
+        {
+            Ok(path) => println!("{}", Overlay::new(heightmap, &path)),
+            Err(err) => {
+                eprintln!("{}", err);
+                std::process::exit(1);
+            }
+        }
+        return;
+    }
+
+    match part1::solve(&input) {
+        Ok(output) => println!("Part 1: {}", output),
+        Err(err) => eprintln!("Part 1: {}", err),
+    }
+
+    match part2::solve(&input) {
+        Ok(output) => println!("Part 2: {}", output),
+        Err(err) => eprintln!("Part 2: {}", err),
+    }
+}
@@ -0,0 +1,57 @@
+use std::fmt::Display;
+
+use crate::input::Heightmap;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct Overlay {
+    width: usize,
+    cells: Vec<char>,
+}
+
+impl Overlay {
+    pub(crate) fn new(heightmap: &Heightmap, path: &[(usize, usize)]) -> Self {
+        let width = heightmap.width;
+        let mut cells = vec!['.'; heightmap.elevations.len()];
+        for step in path.windows(2) {
+            let [(x, y), (next_x, next_y)] = [step[0], step[1]];
+            cells[y * width + x] = match (next_x.cmp(&x), next_y.cmp(&y)) {
+                (std::cmp::Ordering::Greater, _) => '>',
+                (std::cmp::Ordering::Less, _) => '<',
+                (_, std::cmp::Ordering::Greater) => 'v',
+                _ => '^',
+            };
+        }
+        if let Some(&(x, y)) = path.last() {
+            cells[y * width + x] = 'E';
+        }
+        Self { width, cells }
+    }
+}
+
+impl Display for Overlay {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for (y, row) in self.cells.chunks(self.width).enumerate() {
+            if y > 0 {
+                writeln!(f)?;
+            }
+            write!(f, "{}", row.iter().collect::<String>())?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::search::Descent;
+
+    #[test]
+    fn overlay_display() {
+        let heightmap = include_str!("input.txt").parse::<Heightmap>().unwrap();
+        let path = Descent::from(&heightmap).path(heightmap.start).unwrap();
+        assert_eq!(
+            Overlay::new(&heightmap, &path).to_string(),
+            "v..v<<<<\n>v.vv<<^\n.v.v>E^^\n.>v>>>^^\n..>>>>>^"
+        );
+    }
+}
@@ -0,0 +1,43 @@
+use crate::{
+    input::Input,
+    output::Output,
+    search::{Descent, NoPathError},
+};
+
+pub(crate) fn solve(input: &Input) -> Result<Output, NoPathError> {
+    let heightmap = &input.heightmap;
+    let steps = Descent::from(heightmap)
+        .distance(heightmap.start)
+        .ok_or(NoPathError)?;
+    Ok(Output { steps })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_solve() {
+        struct TestCase {
+            input: Input,
+            output: Result<Output, NoPathError>,
+        }
+        let test_cases = [
+            TestCase {
+                input: include_str!("input.txt").parse().unwrap(),
+                output: Ok(Output { steps: 31 }),
+            },
+            TestCase {
+                input: "SE".parse().unwrap(),
+                output: Err(NoPathError),
+            },
+            TestCase {
+                input: "Szy\nabE".parse().unwrap(),
+                output: Err(NoPathError),
+            },
+        ];
+        for tc in test_cases {
+            assert_eq!(solve(&tc.input), tc.output);
+        }
+    }
+}
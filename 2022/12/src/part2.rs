@@ -0,0 +1,45 @@
+use crate::{
+    input::Input,
+    output::Output,
+    search::{Descent, NoPathError, lowest_squares},
+};
+
+pub(crate) fn solve(input: &Input) -> Result<Output, NoPathError> {
+    let heightmap = &input.heightmap;
+    let descent = Descent::from(heightmap);
+    let start = descent
+        .nearest(lowest_squares(heightmap))
+        .ok_or(NoPathError)?;
+    let steps = descent.distance(start).ok_or(NoPathError)?;
+    Ok(Output { steps })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_solve() {
+        struct TestCase {
+            input: Input,
+            output: Result<Output, NoPathError>,
+        }
+        let test_cases = [
+            TestCase {
+                input: include_str!("input.txt").parse().unwrap(),
+                output: Ok(Output { steps: 29 }),
+            },
+            TestCase {
+                input: "SE".parse().unwrap(),
+                output: Err(NoPathError),
+            },
+            TestCase {
+                input: "SabcdefghijklmnopqrstuvwxyE".parse().unwrap(),
+                output: Ok(Output { steps: 25 }),
+            },
+        ];
+        for tc in test_cases {
+            assert_eq!(solve(&tc.input), tc.output);
+        }
+    }
+}
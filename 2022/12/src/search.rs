@@ -0,0 +1,143 @@
+use std::{collections::VecDeque, error::Error, fmt::Display};
+
+use crate::input::Heightmap;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) struct NoPathError;
+
+impl Display for NoPathError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "no path leads to the best signal location")
+    }
+}
+
+impl Error for NoPathError {}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct Descent {
+    width: usize,
+    distances: Vec<Option<u32>>,
+    next: Vec<Option<(usize, usize)>>,
+}
+
+impl From<&Heightmap> for Descent {
+    fn from(heightmap: &Heightmap) -> Self {
+        let width = heightmap.width;
+        let index = |(x, y): (usize, usize)| y * width + x;
+        let mut distances = vec![None; heightmap.elevations.len()];
+        let mut next = vec![None; heightmap.elevations.len()];
+        let mut queue = VecDeque::from([heightmap.end]);
+        distances[index(heightmap.end)] = Some(0);
+        while let Some(position) = queue.pop_front() {
+            let distance = distances[index(position)].unwrap_or(0) + 1;
+            let elevation = heightmap.elevation(position);
+            for neighbour in heightmap.neighbours(position) {
+                if heightmap.elevation(neighbour) + 1 < elevation
+                    || distances[index(neighbour)].is_some()
+                {
+                    continue;
+                }
+                distances[index(neighbour)] = Some(distance);
+                next[index(neighbour)] = Some(position);
+                queue.push_back(neighbour);
+            }
+        }
+        Self {
+            width,
+            distances,
+            next,
+        }
+    }
+}
+
+impl Descent {
+    pub(crate) fn distance(&self, (x, y): (usize, usize)) -> Option<u32> {
+        self.distances[y * self.width + x]
+    }
+
+    pub(crate) fn nearest(
+        &self,
+        candidates: impl IntoIterator<Item = (usize, usize)>,
+    ) -> Option<(usize, usize)> {
+        candidates
+            .into_iter()
+            .filter_map(|position| Some((self.distance(position)?, position)))
+            .min()
+            .map(|(_, position)| position)
+    }
+
+    pub(crate) fn path(
+        &self,
+        from: (usize, usize),
+    ) -> Result<Vec<(usize, usize)>, NoPathError> {
+        self.distance(from).ok_or(NoPathError)?;
+        let mut path = vec![from];
+        let mut position = from;
+        while let Some(next) = self.next[position.1 * self.width + position.0] {
+            path.push(next);
+            position = next;
+        }
+        Ok(path)
+    }
+}
+
+pub(crate) fn lowest_squares(
+    heightmap: &Heightmap,
+) -> impl Iterator<Item = (usize, usize)> {
+    let width = heightmap.width;
+    heightmap
+        .elevations
+        .iter()
+        .enumerate()
+        .filter(|&(_, &elevation)| elevation == 0)
+        .map(move |(index, _)| (index % width, index / width))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn descent_path() {
+        struct TestCase {
+            input: (&'static str, (usize, usize)),
+            output: Result<Vec<(usize, usize)>, NoPathError>,
+        }
+        let test_cases = [
+            TestCase {
+                input: ("SbcdefghijklmnopqrstuvwxyE", (0, 0)),
+                output: Ok((0..26).map(|x| (x, 0)).collect()),
+            },
+            TestCase {
+                input: ("SE", (1, 0)),
+                output: Ok(vec![(1, 0)]),
+            },
+            TestCase {
+                input: ("SE", (0, 0)),
+                output: Err(NoPathError),
+            },
+            TestCase {
+                input: (
+                    "SbcdefghijklmnopqrstuvwxyE\nzzzzzzzzzzzzzzzzzzzzzzzzzz",
+                    (0, 0),
+                ),
+                output: Ok((0..26).map(|x| (x, 0)).collect()),
+            },
+            TestCase {
+                input: (
+                    "SbcdefghijklmnopqrstuvwxEz\nzzzzzzzzzzzzzzzzzzzzzzzyzz",
+                    (0, 0),
+                ),
+                output: Ok((0..24)
+                    .map(|x| (x, 0))
+                    .chain([(23, 1), (24, 1), (24, 0)])
+                    .collect()),
+            },
+        ];
+        for tc in test_cases {
+            let heightmap = tc.input.0.parse::<Heightmap>().unwrap();
+            let descent = Descent::from(&heightmap);
+            assert_eq!(descent.path(tc.input.1), tc.output);
+        }
+    }
+}
@@ -0,0 +1,120 @@
+use std::{error::Error, fmt::Display, str::FromStr};
+
+use crate::packet::{Packet, ParsePacketError};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) enum ParseInputError {
+    IncompletePair {
+        line: usize,
+    },
+    Packet {
+        line: usize,
+        source: ParsePacketError,
+    },
+}
+
+impl Display for ParseInputError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match *self {
+            Self::IncompletePair { line } => {
+                write!(f, "expected a pair of packets on line {}", line)
+            }
+            Self::Packet { line, ref source } => {
+                write!(f, "failed to parse packet on line {}: {}", line, source)
+            }
+        }
+    }
+}
+
+impl Error for ParseInputError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match *self {
+            Self::IncompletePair { .. } => None,
+            Self::Packet { ref source, .. } => Some(source),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct Input {
+    pub(crate) pairs: Vec<(Packet, Packet)>,
+}
+
+impl FromStr for Input {
+    type Err = ParseInputError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut pairs = Vec::new();
+        let mut lines =
+            s.lines().enumerate().map(|(index, line)| (index + 1, line));
+        let packet = |(line, text): (usize, &str)| {
+            text.parse::<Packet>()
+                .map_err(|source| ParseInputError::Packet { line, source })
+        };
+        while let Some((line, text)) = lines.next() {
+            if text.is_empty() {
+                continue;
+            }
+            let left = packet((line, text))?;
+            let right = match lines.next() {
+                Some((line, text)) if !text.is_empty() => packet((line, text))?,
+                _ => return Err(ParseInputError::IncompletePair { line }),
+            };
+            pairs.push((left, right));
+        }
+        Ok(Self { pairs })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn input_from_str() {
+        struct TestCase {
+            input: &'static str,
+            output: Result<Vec<(&'static str, &'static str)>, ParseInputError>,
+        }
+        let test_cases = [
+            TestCase {
+                input: "[1]\n[2]\n\n[[]]\n3\n",
+                output: Ok(vec![("[1]", "[2]"), ("[[]]", "3")]),
+            },
+            TestCase {
+                input: "[1]\n[2]\n\n[3]\n\n[4]",
+                output: Err(ParseInputError::IncompletePair { line: 4 }),
+            },
+            TestCase {
+                input: "[1]",
+                output: Err(ParseInputError::IncompletePair { line: 1 }),
+            },
+            TestCase {
+                input: "[1]\n[2,]",
+                output: Err(ParseInputError::Packet {
+                    line: 2,
+                    source: ParsePacketError::UnexpectedCharacter {
+                        character: ']',
+                        column: 4,
+                    },
+                }),
+            },
+        ];
+        for tc in test_cases {
+            let result = tc.input.parse::<Input>().map(|input| {
+                input
+                    .pairs
+                    .iter()
+                    .map(|(left, right)| (left.to_string(), right.to_string()))
+                    .collect::<Vec<_>>()
+            });
+            let output = tc.output.map(|pairs| {
+                pairs
+                    .into_iter()
+                    .map(|(left, right)| (left.to_string(), right.to_string()))
+                    .collect::<Vec<_>>()
+            });
+            assert_eq!(result, output);
+        }
+    }
+}
@@ -0,0 +1,299 @@
+use std::{cmp::Ordering, error::Error, fmt::Display, str::FromStr};
+
+#[derive(Debug, Clone)]
+pub(crate) enum Packet {
+    Integer(u32),
+    List(Vec<Packet>),
+}
+
+impl Ord for Packet {
+    fn cmp(&self, other: &Self) -> Ordering {
+        match (self, other) {
+            (Self::Integer(left), Self::Integer(right)) => left.cmp(right),
+            (Self::List(left), Self::List(right)) => left.cmp(right),
+            (&Self::Integer(left), Self::List(right)) => {
+                [Self::Integer(left)][..].cmp(right)
+            }
+            (Self::List(left), &Self::Integer(right)) => {
+                left[..].cmp(&[Self::Integer(right)])
+            }
+        }
+    }
+}
+
+impl PartialEq for Packet {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for Packet {}
+
+impl PartialOrd for Packet {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Display for Packet {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match *self {
+            Self::Integer(value) => write!(f, "{}", value),
+            Self::List(ref packets) => {
+                write!(f, "[")?;
+                for (index, packet) in packets.iter().enumerate() {
+                    if index > 0 {
+                        write!(f, ",")?;
+                    }
+                    write!(f, "{}", packet)?;
+                }
+                write!(f, "]")
+            }
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) enum ParsePacketError {
+    Empty,
+    UnexpectedCharacter { character: char, column: usize },
+    UnexpectedEnd,
+    IntegerOverflow { column: usize },
+}
+
+impl Display for ParsePacketError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match *self {
+            Self::Empty => write!(f, "cannot parse packet from empty string"),
+            Self::UnexpectedCharacter { character, column } => write!(
+                f,
+                "unexpected character {:?} at column {}",
+                character, column
+            ),
+            Self::UnexpectedEnd => write!(f, "unexpected end of packet"),
+            Self::IntegerOverflow { column } => {
+                write!(f, "integer too large at column {}", column)
+            }
+        }
+    }
+}
+
+impl Error for ParsePacketError {}
+
+struct Parser<'a> {
+    chars: std::iter::Peekable<std::iter::Enumerate<std::str::Chars<'a>>>,
+}
+
+impl Parser<'_> {
+    fn unexpected(&mut self) -> ParsePacketError {
+        match self.chars.next() {
+            Some((index, character)) => ParsePacketError::UnexpectedCharacter {
+                character,
+                column: index + 1,
+            },
+            None => ParsePacketError::UnexpectedEnd,
+        }
+    }
+
+    fn packet(&mut self) -> Result<Packet, ParsePacketError> {
+        match self.chars.peek() {
+            Some(&(_, '[')) => self.list(),
+            Some(&(_, '0'..='9')) => self.integer(),
+            _ => Err(self.unexpected()),
+        }
+    }
+
+    fn integer(&mut self) -> Result<Packet, ParsePacketError> {
+        let mut value = 0u32;
+        while let Some(&(index, character)) = self.chars.peek() {
+            let Some(digit) = character.to_digit(10) else {
+                break;
+            };
+            value = value
+                .checked_mul(10)
+                .and_then(|value| value.checked_add(digit))
+                .ok_or(ParsePacketError::IntegerOverflow {
+                    column: index + 1,
+                })?;
+            self.chars.next();
+        }
+        Ok(Packet::Integer(value))
+    }
+
+    fn list(&mut self) -> Result<Packet, ParsePacketError> {
+        self.chars.next();
+        let mut packets = Vec::new();
+        if self
+            .chars
+            .next_if(|&(_, character)| character == ']')
+            .is_some()
+        {
+            return Ok(Packet::List(packets));
+        }
+        loop {
+            packets.push(self.packet()?);
+            match self.chars.peek() {
+                Some(&(_, ',')) => {
+                    self.chars.next();
+                }
+                Some(&(_, ']')) => {
+                    self.chars.next();
+                    return Ok(Packet::List(packets));
+                }
+                _ => return Err(self.unexpected()),
+            }
+        }
+    }
+}
+
+impl FromStr for Packet {
+    type Err = ParsePacketError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s.is_empty() {
+            return Err(ParsePacketError::Empty);
+        }
+        let mut parser = Parser {
+            chars: s.chars().enumerate().peekable(),
+        };
+        let packet = parser.packet()?;
+        if parser.chars.peek().is_some() {
+            return Err(parser.unexpected());
+        }
+        Ok(packet)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn list<const N: usize>(packets: [Packet; N]) -> Packet {
+        Packet::List(packets.to_vec())
+    }
+
+    #[test]
+    fn packet_from_str() {
+        struct TestCase {
+            input: &'static str,
+            output: Result<Packet, ParsePacketError>,
+        }
+        let test_cases = [
+            TestCase {
+                input: "[1,[2,[]],10]",
+                output: Ok(list([
+                    Packet::Integer(1),
+                    list([Packet::Integer(2), list([])]),
+                    Packet::Integer(10),
+                ])),
+            },
+            TestCase {
+                input: "7",
+                output: Ok(Packet::Integer(7)),
+            },
+            TestCase {
+                input: "",
+                output: Err(ParsePacketError::Empty),
+            },
+            TestCase {
+                input: "[1,,2]",
+                output: Err(ParsePacketError::UnexpectedCharacter {
+                    character: ',',
+                    column: 4,
+                }),
+            },
+            TestCase {
+                input: "[1 2]",
+                output: Err(ParsePacketError::UnexpectedCharacter {
+                    character: ' ',
+                    column: 3,
+                }),
+            },
+            TestCase {
+                input: "[1,[2]",
+                output: Err(ParsePacketError::UnexpectedEnd),
+            },
+            TestCase {
+                input: "[1,]",
+                output: Err(ParsePacketError::UnexpectedCharacter {
+                    character: ']',
+                    column: 4,
+                }),
+            },
+            TestCase {
+                input: "[1]]",
+                output: Err(ParsePacketError::UnexpectedCharacter {
+                    character: ']',
+                    column: 4,
+                }),
+            },
+            TestCase {
+                input: "[99999999999]",
+                output: Err(ParsePacketError::IntegerOverflow { column: 11 }),
+            },
+        ];
+        for tc in test_cases {
+            let result = tc.input.parse::<Packet>();
+            assert_eq!(format!("{:?}", result), format!("{:?}", tc.output));
+        }
+    }
+
+    #[test]
+    fn packet_display() {
+        for packet in ["[]", "[[[]]]", "[1,[2,[3,[4,[5,6,7]]]],8,9]", "12"] {
+            assert_eq!(packet.parse::<Packet>().unwrap().to_string(), packet);
+        }
+    }
+
+    #[test]
+    fn packet_cmp() {
+        struct TestCase {
+            input: (&'static str, &'static str),
+            output: Ordering,
+        }
+        let test_cases = [
+            TestCase {
+                input: ("[1,1,3,1,1]", "[1,1,5,1,1]"),
+                output: Ordering::Less,
+            },
+            TestCase {
+                input: ("[[1],[2,3,4]]", "[[1],4]"),
+                output: Ordering::Less,
+            },
+            TestCase {
+                input: ("[9]", "[[8,7,6]]"),
+                output: Ordering::Greater,
+            },
+            TestCase {
+                input: ("[[4,4],4,4]", "[[4,4],4,4,4]"),
+                output: Ordering::Less,
+            },
+            TestCase {
+                input: ("[7,7,7,7]", "[7,7,7]"),
+                output: Ordering::Greater,
+            },
+            TestCase {
+                input: ("[]", "[3]"),
+                output: Ordering::Less,
+            },
+            TestCase {
+                input: ("[[[]]]", "[[]]"),
+                output: Ordering::Greater,
+            },
+            TestCase {
+                input: ("[2]", "[[[2]]]"),
+                output: Ordering::Equal,
+            },
+            TestCase {
+                input: ("3", "[[3],[]]"),
+                output: Ordering::Less,
+            },
+        ];
+        for tc in test_cases {
+            let left = tc.input.0.parse::<Packet>().unwrap();
+            let right = tc.input.1.parse::<Packet>().unwrap();
+            assert_eq!(left.cmp(&right), tc.output);
+            assert_eq!(right.cmp(&left), tc.output.reverse());
+        }
+    }
+}
@@ -0,0 +1,39 @@
+use crate::{input::Input, output::Output};
+
+pub(crate) fn solve(input: &Input) -> Output {
+    Output {
+        value: input
+            .pairs
+            .iter()
+            .enumerate()
+            .filter(|(_, (left, right))| left < right)
+            .map(|(index, _)| index + 1)
+            .sum(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_solve() {
+        struct TestCase {
+            input: Input,
+            output: Output,
+        }
+        let test_cases = [
+            TestCase {
+                input: include_str!("input.txt").parse().unwrap(),
+                output: Output { value: 13 },
+            },
+            TestCase {
+                input: "[1]\n[1]\n\n[1]\n[[1],2]".parse().unwrap(),
+                output: Output { value: 2 },
+            },
+        ];
+        for tc in test_cases {
+            assert_eq!(solve(&tc.input), tc.output);
+        }
+    }
+}
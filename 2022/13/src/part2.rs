@@ -0,0 +1,50 @@
+use crate::{input::Input, output::Output, packet::Packet};
+
+pub(crate) fn solve(input: &Input) -> Output {
+    let divider =
+        |value| Packet::List(vec![Packet::List(vec![Packet::Integer(value)])]);
+    let (first, second) = (divider(2), divider(6));
+    let packets = input.pairs.iter().flat_map(|(left, right)| [left, right]);
+    let (mut first_index, mut second_index) = (1, 2);
+    for packet in packets {
+        if *packet < first {
+            first_index += 1;
+        }
+        if *packet < second {
+            second_index += 1;
+        }
+    }
+    Output {
+        value: first_index * second_index,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_solve() {
+        struct TestCase {
+            input: Input,
+            output: Output,
+        }
+        let test_cases = [
+            TestCase {
+                input: include_str!("input.txt").parse().unwrap(),
+                output: Output { value: 140 },
+            },
+            TestCase {
+                input: "".parse().unwrap(),
+                output: Output { value: 2 },
+            },
+            TestCase {
+                input: "[7]\n[[1]]".parse().unwrap(),
+                output: Output { value: 6 },
+            },
+        ];
+        for tc in test_cases {
+            assert_eq!(solve(&tc.input), tc.output);
+        }
+    }
+}
@@ -0,0 +1,220 @@
+use std::fmt::Display;
+
+pub(crate) const SOURCE: (usize, usize) = (500, 0);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum Tile {
+    Air,
+    Rock,
+    Sand,
+}
+
+impl Display for Tile {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match *self {
+            Self::Air => write!(f, "."),
+            Self::Rock => write!(f, "#"),
+            Self::Sand => write!(f, "o"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct Cave {
+    source: usize,
+    width: usize,
+    height: usize,
+    floor: bool,
+    tiles: Vec<Tile>,
+}
+
+impl Cave {
+    pub(crate) fn new(paths: &[Vec<(usize, usize)>], floor: bool) -> Self {
+        let max_y = paths.iter().flatten().map(|&(_, y)| y).max().unwrap_or(0);
+        let height = max_y + 3;
+        let width = 2 * height + 1;
+        let mut cave = Self {
+            source: height,
+            width,
+            height,
+            floor,
+            tiles: vec![Tile::Air; width * height],
+        };
+        for path in paths {
+            for segment in path.windows(2) {
+                let ((from_x, from_y), (to_x, to_y)) = (segment[0], segment[1]);
+                for x in from_x.min(to_x)..=from_x.max(to_x) {
+                    for y in from_y.min(to_y)..=from_y.max(to_y) {
+                        if let Some(column) = cave.column(x) {
+                            cave.set((column, y), Tile::Rock);
+                        }
+                    }
+                }
+            }
+            if let [(x, y)] = path[..]
+                && let Some(column) = cave.column(x)
+            {
+                cave.set((column, y), Tile::Rock);
+            }
+        }
+        if floor {
+            for column in 0..width {
+                cave.set((column, height - 1), Tile::Rock);
+            }
+        }
+        cave
+    }
+
+    fn column(&self, x: usize) -> Option<usize> {
+        (x + self.source)
+            .checked_sub(SOURCE.0)
+            .filter(|&column| column < self.width)
+    }
+
+    fn index(&self, (column, y): (usize, usize)) -> Option<usize> {
+        (column < self.width && y < self.height)
+            .then(|| y * self.width + column)
+    }
+
+    fn get(&self, position: (usize, usize)) -> Tile {
+        self.index(position)
+            .map_or(Tile::Air, |index| self.tiles[index])
+    }
+
+    fn set(&mut self, position: (usize, usize), tile: Tile) {
+        if let Some(index) = self.index(position) {
+            self.tiles[index] = tile;
+        }
+    }
+
+    pub(crate) fn pour(&mut self) -> usize {
+        let abyss = self.height - 2;
+        let mut path = vec![(self.source, SOURCE.1)];
+        let mut grains = 0;
+        while let Some(&(column, y)) = path.last() {
+            if !self.floor && y >= abyss {
+                break;
+            }
+            let next =
+                [(column, y + 1), (column - 1, y + 1), (column + 1, y + 1)]
+                    .into_iter()
+                    .find(|&position| self.get(position) == Tile::Air);
+            match next {
+                Some(position) => path.push(position),
+                None => {
+                    self.set((column, y), Tile::Sand);
+                    grains += 1;
+                    path.pop();
+                }
+            }
+        }
+        grains
+    }
+}
+
+impl Display for Cave {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let source = (self.source, SOURCE.1);
+        let occupied = (0..self.tiles.len())
+            .filter(|&index| self.tiles[index] != Tile::Air)
+            .map(|index| (index % self.width, index / self.width))
+            .chain([source]);
+        let (min_column, max_column, max_y) = occupied.fold(
+            (source.0, source.0, source.1),
+            |(min_column, max_column, max_y), (column, y)| {
+                (min_column.min(column), max_column.max(column), max_y.max(y))
+            },
+        );
+        for y in 0..=max_y {
+            if y > 0 {
+                writeln!(f)?;
+            }
+            for column in min_column..=max_column {
+                if (column, y) == source && self.get(source) == Tile::Air {
+                    write!(f, "+")?;
+                } else {
+                    write!(f, "{}", self.get((column, y)))?;
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::input::Input;
+
+    #[test]
+    fn cave_pour() {
+        struct TestCase {
+            input: (&'static str, bool),
+            output: usize,
+        }
+        let test_cases = [
+            TestCase {
+                input: (include_str!("input.txt"), false),
+                output: 24,
+            },
+            TestCase {
+                input: (include_str!("input.txt"), true),
+                output: 93,
+            },
+            TestCase {
+                input: ("", true),
+                output: 4,
+            },
+            TestCase {
+                input: ("499,2 -> 501,2", false),
+                output: 1,
+            },
+            TestCase {
+                input: ("500,1", false),
+                output: 0,
+            },
+            TestCase {
+                input: ("500,600", true),
+                output: 602 * 602 - 1,
+            },
+        ];
+        for tc in test_cases {
+            let input = tc.input.0.parse::<Input>().unwrap();
+            let mut cave = Cave::new(&input.paths, tc.input.1);
+            assert_eq!(cave.pour(), tc.output);
+        }
+    }
+
+    #[test]
+    fn cave_display() {
+        let input = include_str!("input.txt").parse::<Input>().unwrap();
+        let mut cave = Cave::new(&input.paths, false);
+        assert_eq!(
+            cave.to_string(),
+            "......+...\n\
+             ..........\n\
+             ..........\n\
+             ..........\n\
+             ....#...##\n\
+             ....#...#.\n\
+             ..###...#.\n\
+             ........#.\n\
+             ........#.\n\
+             #########."
+        );
+        cave.pour();
+        assert_eq!(
+            cave.to_string(),
+            "......+...\n\
+             ..........\n\
+             ......o...\n\
+             .....ooo..\n\
+             ....#ooo##\n\
+             ...o#ooo#.\n\
+             ..###ooo#.\n\
+             ....oooo#.\n\
+             .o.ooooo#.\n\
+             #########."
+        );
+    }
+}
@@ -0,0 +1,154 @@
+use std::{error::Error, fmt::Display, num::ParseIntError, str::FromStr};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) enum ParsePathError {
+    InvalidPoint(String),
+    ParseCoordinate(ParseIntError),
+    DiagonalSegment {
+        from: (usize, usize),
+        to: (usize, usize),
+    },
+}
+
+impl Display for ParsePathError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match *self {
+            Self::InvalidPoint(ref point) => {
+                write!(f, "invalid format for point: {}", point)
+            }
+            Self::ParseCoordinate(ref err) => {
+                write!(f, "failed to parse coordinate: {}", err)
+            }
+            Self::DiagonalSegment {
+                from: (from_x, from_y),
+                to: (to_x, to_y),
+            } => write!(
+                f,
+                "segment from {},{} to {},{} is not horizontal or vertical",
+                from_x, from_y, to_x, to_y
+            ),
+        }
+    }
+}
+
+impl Error for ParsePathError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match *self {
+            Self::ParseCoordinate(ref err) => Some(err),
+            _ => None,
+        }
+    }
+}
+
+fn parse_path(s: &str) -> Result<Vec<(usize, usize)>, ParsePathError> {
+    let points = s
+        .split(" -> ")
+        .map(|point| {
+            let (x, y) = point.split_once(',').ok_or_else(|| {
+                ParsePathError::InvalidPoint(point.to_string())
+            })?;
+            let x = x.parse().map_err(ParsePathError::ParseCoordinate)?;
+            let y = y.parse().map_err(ParsePathError::ParseCoordinate)?;
+            Ok((x, y))
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+    for segment in points.windows(2) {
+        let (from, to) = (segment[0], segment[1]);
+        if from.0 != to.0 && from.1 != to.1 {
+            return Err(ParsePathError::DiagonalSegment { from, to });
+        }
+    }
+    Ok(points)
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct ParseInputError {
+    pub(crate) line: usize,
+    pub(crate) source: ParsePathError,
+}
+
+impl Display for ParseInputError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "failed to parse rock path on line {}: {}",
+            self.line, self.source
+        )
+    }
+}
+
+impl Error for ParseInputError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        Some(&self.source)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct Input {
+    pub(crate) paths: Vec<Vec<(usize, usize)>>,
+}
+
+impl FromStr for Input {
+    type Err = ParseInputError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let paths = s
+            .lines()
+            .enumerate()
+            .map(|(index, line)| {
+                parse_path(line).map_err(|source| ParseInputError {
+                    line: index + 1,
+                    source,
+                })
+            })
+            .collect::<Result<_, _>>()?;
+        Ok(Self { paths })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn input_from_str() {
+        struct TestCase {
+            input: &'static str,
+            output: Result<Input, String>,
+        }
+        let test_cases = [
+            TestCase {
+                input: "498,4 -> 498,6 -> 496,6\n503,4 -> 502,4",
+                output: Ok(Input {
+                    paths: vec![
+                        vec![(498, 4), (498, 6), (496, 6)],
+                        vec![(503, 4), (502, 4)],
+                    ],
+                }),
+            },
+            TestCase {
+                input: "498,4 -> 498;6",
+                output: Err("failed to parse rock path on line 1: invalid \
+                             format for point: 498;6"
+                    .to_string()),
+            },
+            TestCase {
+                input: "498,4\n498,x",
+                output: Err("failed to parse rock path on line 2: failed to \
+                             parse coordinate: invalid digit found in string"
+                    .to_string()),
+            },
+            TestCase {
+                input: "498,4 -> 499,5",
+                output: Err("failed to parse rock path on line 1: segment \
+                             from 498,4 to 499,5 is not horizontal or vertical"
+                    .to_string()),
+            },
+        ];
+        for tc in test_cases {
+            let result =
+                tc.input.parse::<Input>().map_err(|err| err.to_string());
+            assert_eq!(result, tc.output);
+        }
+    }
+}
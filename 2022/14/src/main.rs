@@ -0,0 +1,29 @@
+mod cave;
+mod input;
+mod output;
+mod part1;
+mod part2;
+
+use cave::Cave;
+use input::Input;
+
+fn main() {
+    let input = include_str!("input.txt")
+        .parse::<Input>()
+        .expect("Input should be valid");
+
+    let args = std::env::args().skip(1).collect::<Vec<_>>();
+    if let Some("dump") = args.first().map(String::as_str) {
+        let floor = args.iter().any(|arg| arg == "--floor");
+        let mut cave = Cave::new(&input.paths, floor);
+        cave.pour();
+        println!("{}", cave);
+        return;
+    }
+
+    let output = part1::solve(&input);
+    println!("Part 1: {}", output);
+
+    let output = part2::solve(&input);
+    println!("Part 2: {}", output);
+}
@@ -0,0 +1,28 @@
+use crate::{cave::Cave, input::Input, output::Output};
+
+pub(crate) fn solve(input: &Input) -> Output {
+    let mut cave = Cave::new(&input.paths, true);
+    Output {
+        grains: cave.pour(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_solve() {
+        struct TestCase {
+            input: Input,
+            output: Output,
+        }
+        let test_cases = [TestCase {
+            input: include_str!("input.txt").parse().unwrap(),
+            output: Output { grains: 93 },
+        }];
+        for tc in test_cases {
+            assert_eq!(solve(&tc.input), tc.output);
+        }
+    }
+}
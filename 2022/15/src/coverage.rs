@@ -0,0 +1,178 @@
+use crate::input::Sensor;
+
+pub(crate) fn row_intervals(sensors: &[Sensor], y: i64) -> Vec<(i64, i64)> {
+    let mut intervals = sensors
+        .iter()
+        .filter_map(|sensor| {
+            let reach = sensor.radius() - (sensor.position.1 - y).abs();
+            (reach >= 0)
+                .then(|| (sensor.position.0 - reach, sensor.position.0 + reach))
+        })
+        .collect::<Vec<_>>();
+    intervals.sort_unstable();
+    let mut merged: Vec<(i64, i64)> = Vec::with_capacity(intervals.len());
+    for (start, end) in intervals {
+        match merged.last_mut() {
+            Some(last) if start <= last.1 + 1 => last.1 = last.1.max(end),
+            _ => merged.push((start, end)),
+        }
+    }
+    merged
+}
+
+pub(crate) fn is_covered(sensors: &[Sensor], position: (i64, i64)) -> bool {
+    sensors
+        .iter()
+        .any(|sensor| sensor.distance(position) <= sensor.radius())
+}
+
+pub(crate) fn find_gap(sensors: &[Sensor], size: i64) -> Option<(i64, i64)> {
+    let (mut rising, mut falling) = (Vec::new(), Vec::new());
+    for sensor in sensors {
+        let (x, y) = sensor.position;
+        let reach = sensor.radius() + 1;
+        rising.extend([x - y - reach, x - y + reach]);
+        falling.extend([x + y - reach, x + y + reach]);
+    }
+    let intersections = rising.iter().flat_map(|&rising| {
+        falling
+            .iter()
+            .filter(move |&&falling| (falling - rising) % 2 == 0)
+            .map(move |&falling| {
+                ((falling + rising) / 2, (falling - rising) / 2)
+            })
+    });
+    let edges = rising
+        .iter()
+        .flat_map(|&c| [(0, -c), (size, size - c), (c, 0), (c + size, size)])
+        .chain(falling.iter().flat_map(|&c| {
+            [(0, c), (size, c - size), (c, 0), (c - size, size)]
+        }));
+    let corners = [(0, 0), (size, 0), (0, size), (size, size)];
+    intersections.chain(edges).chain(corners).find(|&(x, y)| {
+        (0..=size).contains(&x)
+            && (0..=size).contains(&y)
+            && !is_covered(sensors, (x, y))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::input::{Input, Sensor};
+
+    #[test]
+    fn test_row_intervals() {
+        struct TestCase {
+            input: (&'static str, i64),
+            output: Vec<(i64, i64)>,
+        }
+        let test_cases = [
+            TestCase {
+                input: (include_str!("input.txt"), 10),
+                output: vec![(-2, 24)],
+            },
+            TestCase {
+                input: (include_str!("input.txt"), 11),
+                output: vec![(-3, 13), (15, 25)],
+            },
+            TestCase {
+                input: (
+                    "Sensor at x=0, y=0: closest beacon is at x=1, y=0\n\
+                     Sensor at x=3, y=0: closest beacon is at x=4, y=0",
+                    0,
+                ),
+                output: vec![(-1, 4)],
+            },
+            TestCase {
+                input: ("Sensor at x=0, y=0: closest beacon is at x=1, y=0", 2),
+                output: vec![],
+            },
+        ];
+        for tc in test_cases {
+            let input = tc.input.0.parse::<Input>().unwrap();
+            assert_eq!(row_intervals(&input.sensors, tc.input.1), tc.output);
+        }
+    }
+
+    #[test]
+    fn test_find_gap() {
+        struct TestCase {
+            input: (&'static str, i64),
+            output: Option<(i64, i64)>,
+        }
+        let test_cases = [
+            TestCase {
+                input: (include_str!("input.txt"), 20),
+                output: Some((14, 11)),
+            },
+            TestCase {
+                input: ("Sensor at x=2, y=2: closest beacon is at x=2, y=6", 4),
+                output: None,
+            },
+            TestCase {
+                input: ("Sensor at x=0, y=0: closest beacon is at x=3, y=0", 2),
+                output: Some((2, 2)),
+            },
+            TestCase {
+                input: (
+                    "Sensor at x=4, y=2: closest beacon is at x=1, y=2\n\
+                     Sensor at x=1, y=-1: closest beacon is at x=4, y=-1\n\
+                     Sensor at x=1, y=5: closest beacon is at x=4, y=5",
+                    4,
+                ),
+                output: Some((0, 2)),
+            },
+        ];
+        for tc in test_cases {
+            let input = tc.input.0.parse::<Input>().unwrap();
+            assert_eq!(find_gap(&input.sensors, tc.input.1), tc.output);
+        }
+    }
+
+    #[test]
+    fn find_gap_matches_brute_force() {
+        let size = 12;
+        let mut state = 0x9e37_79b9_7f4a_7c15_u64;
+        let mut next = |bound: i64| {
+            state ^= state << 13;
+            state ^= state >> 7;
+            state ^= state << 17;
+            (state % bound as u64) as i64
+        };
+        let mut checked = 0;
+        for _ in 0..2000 {
+            let gap = match next(3) {
+                0 => (next(size + 1), next(size + 1)),
+                1 => (next(2) * size, next(size + 1)),
+                _ => (next(size + 1), next(2) * size),
+            };
+            let sensors = (0..8)
+                .map(|_| {
+                    let position = (next(size + 7) - 3, next(size + 7) - 3);
+                    let distance =
+                        (position.0 - gap.0).abs() + (position.1 - gap.1).abs();
+                    Sensor {
+                        position,
+                        beacon: (position.0 + distance - 1, position.1),
+                    }
+                })
+                .filter(|sensor| sensor.radius() > 0)
+                .collect::<Vec<_>>();
+            let gaps = (0..=size)
+                .flat_map(|x| (0..=size).map(move |y| (x, y)))
+                .filter(|&position| !is_covered(&sensors, position))
+                .collect::<Vec<_>>();
+            if gaps == [gap] {
+                assert_eq!(
+                    find_gap(&sensors, size),
+                    Some(gap),
+                    "{:?}",
+                    sensors
+                );
+                checked += 1;
+            }
+        }
+        assert!(checked > 100, "only {} unique gaps checked", checked);
+    }
+}
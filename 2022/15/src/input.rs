@@ -0,0 +1,172 @@
+use std::{error::Error, fmt::Display, num::ParseIntError, str::FromStr};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) struct Sensor {
+    pub(crate) position: (i64, i64),
+    pub(crate) beacon: (i64, i64),
+}
+
+impl Sensor {
+    pub(crate) fn radius(&self) -> i64 {
+        self.distance(self.beacon)
+    }
+
+    pub(crate) fn distance(&self, (x, y): (i64, i64)) -> i64 {
+        (self.position.0 - x).abs() + (self.position.1 - y).abs()
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) enum ParseSensorError {
+    InvalidFormat(String),
+    ParseCoordinate(ParseIntError),
+}
+
+impl Display for ParseSensorError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match *self {
+            Self::InvalidFormat(ref sensor) => {
+                write!(f, "invalid format for sensor: {}", sensor)
+            }
+            Self::ParseCoordinate(ref err) => {
+                write!(f, "failed to parse coordinate: {}", err)
+            }
+        }
+    }
+}
+
+impl Error for ParseSensorError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match *self {
+            Self::InvalidFormat(_) => None,
+            Self::ParseCoordinate(ref err) => Some(err),
+        }
+    }
+}
+
+fn parse_point(s: &str) -> Result<(i64, i64), ParseSensorError> {
+    let invalid = || ParseSensorError::InvalidFormat(s.to_string());
+    let (x, y) = s
+        .strip_prefix("x=")
+        .and_then(|point| point.split_once(", y="))
+        .ok_or_else(invalid)?;
+    let x = x.parse().map_err(ParseSensorError::ParseCoordinate)?;
+    let y = y.parse().map_err(ParseSensorError::ParseCoordinate)?;
+    Ok((x, y))
+}
+
+impl FromStr for Sensor {
+    type Err = ParseSensorError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (position, beacon) = s
+            .strip_prefix("Sensor at ")
+            .and_then(|sensor| sensor.split_once(": closest beacon is at "))
+            .ok_or_else(|| ParseSensorError::InvalidFormat(s.to_string()))?;
+        Ok(Self {
+            position: parse_point(position)?,
+            beacon: parse_point(beacon)?,
+        })
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct ParseInputError {
+    pub(crate) line: usize,
+    pub(crate) source: ParseSensorError,
+}
+
+impl Display for ParseInputError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "failed to parse sensor on line {}: {}",
+            self.line, self.source
+        )
+    }
+}
+
+impl Error for ParseInputError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        Some(&self.source)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct Input {
+    pub(crate) sensors: Vec<Sensor>,
+}
+
+impl FromStr for Input {
+    type Err = ParseInputError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let sensors = s
+            .lines()
+            .enumerate()
+            .map(|(index, line)| {
+                line.parse().map_err(|source| ParseInputError {
+                    line: index + 1,
+                    source,
+                })
+            })
+            .collect::<Result<_, _>>()?;
+        Ok(Self { sensors })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn input_from_str() {
+        struct TestCase {
+            input: &'static str,
+            output: Result<Input, String>,
+        }
+        let test_cases = [
+            TestCase {
+                input: "Sensor at x=2, y=18: closest beacon is at x=-2, y=15\n\
+                        Sensor at x=-9, y=-16: closest beacon is at x=10, \
+                        y=16",
+                output: Ok(Input {
+                    sensors: vec![
+                        Sensor {
+                            position: (2, 18),
+                            beacon: (-2, 15),
+                        },
+                        Sensor {
+                            position: (-9, -16),
+                            beacon: (10, 16),
+                        },
+                    ],
+                }),
+            },
+            TestCase {
+                input: "Sensor at x=2, y=18; closest beacon is at x=-2, y=15",
+                output: Err("failed to parse sensor on line 1: invalid \
+                             format for sensor: Sensor at x=2, y=18; closest \
+                             beacon is at x=-2, y=15"
+                    .to_string()),
+            },
+            TestCase {
+                input: "Sensor at x=2 y=18: closest beacon is at x=-2, y=15",
+                output: Err("failed to parse sensor on line 1: invalid \
+                             format for sensor: x=2 y=18"
+                    .to_string()),
+            },
+            TestCase {
+                input: "Sensor at x=2, y=18: closest beacon is at x=--2, y=15",
+                output: Err("failed to parse sensor on line 1: failed to \
+                             parse coordinate: invalid digit found in string"
+                    .to_string()),
+            },
+        ];
+        for tc in test_cases {
+            let result =
+                tc.input.parse::<Input>().map_err(|err| err.to_string());
+            assert_eq!(result, tc.output);
+        }
+    }
+}
@@ -0,0 +1,45 @@
+mod coverage;
+mod input;
+mod output;
+mod part1;
+mod part2;
+
+use input::Input;
+
+const USAGE: &str = "usage: day15 [--row <y>] [--size <max>]
+defaults fit the bundled example (--row 10 --size 20); \
+full puzzle inputs use --row 2000000 --size 4000000";
+
+fn flags(args: &[String]) -> Option<(i64, i64)> {
+    let (mut row, mut size) = (part1::ROW, part2::SIZE);
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        let value = match arg.as_str() {
+            "--row" => &mut row,
+            "--size" => &mut size,
+            _ => return None,
+        };
+        *value = args.next()?.parse().ok()?;
+    }
+    Some((row, size))
+}
+
+fn main() {
+    let input = include_str!("input.txt")
+        .parse::<Input>()
+        .expect("Input should be valid");
+
+    let args = std::env::args().skip(1).collect::<Vec<_>>();
+    let Some((row, size)) = flags(&args) else {
+        eprintln!("{}", USAGE);
+        std::process::exit(2);
+    };
+
+    let output = part1::solve(&input, row);
+    println!("Part 1: {}", output);
+
+    match part2::solve(&input, size) {
+        Some(output) => println!("Part 2: {}", output),
+        None => eprintln!("Part 2: every position is covered by a sensor"),
+    }
+}
@@ -0,0 +1,62 @@
+use std::collections::HashSet;
+
+use crate::{coverage::row_intervals, input::Input, output::Output};
+
+pub(crate) const ROW: i64 = 10;
+
+pub(crate) fn solve(input: &Input, row: i64) -> Output {
+    let intervals = row_intervals(&input.sensors, row);
+    let covered = intervals
+        .iter()
+        .map(|&(start, end)| end - start + 1)
+        .sum::<i64>();
+    let beacons = input
+        .sensors
+        .iter()
+        .map(|sensor| sensor.beacon)
+        .filter(|&(x, y)| {
+            y == row
+                && intervals
+                    .iter()
+                    .any(|&(start, end)| (start..=end).contains(&x))
+        })
+        .collect::<HashSet<_>>();
+    Output {
+        value: covered - beacons.len() as i64,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_solve() {
+        struct TestCase {
+            input: (Input, i64),
+            output: Output,
+        }
+        let test_cases = [
+            TestCase {
+                input: (include_str!("input.txt").parse().unwrap(), 10),
+                output: Output { value: 26 },
+            },
+            TestCase {
+                input: (include_str!("input.txt").parse().unwrap(), 16),
+                output: Output { value: 29 },
+            },
+            TestCase {
+                input: (
+                    "Sensor at x=0, y=0: closest beacon is at x=2, y=0"
+                        .parse()
+                        .unwrap(),
+                    0,
+                ),
+                output: Output { value: 4 },
+            },
+        ];
+        for tc in test_cases {
+            assert_eq!(solve(&tc.input.0, tc.input.1), tc.output);
+        }
+    }
+}
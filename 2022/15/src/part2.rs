@@ -0,0 +1,42 @@
+use crate::{coverage::find_gap, input::Input, output::Output};
+
+pub(crate) const SIZE: i64 = 20;
+const FREQUENCY_MULTIPLIER: i64 = 4_000_000;
+
+pub(crate) fn solve(input: &Input, size: i64) -> Option<Output> {
+    let (x, y) = find_gap(&input.sensors, size)?;
+    Some(Output {
+        value: x * FREQUENCY_MULTIPLIER + y,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_solve() {
+        struct TestCase {
+            input: (Input, i64),
+            output: Option<Output>,
+        }
+        let test_cases = [
+            TestCase {
+                input: (include_str!("input.txt").parse().unwrap(), 20),
+                output: Some(Output { value: 56000011 }),
+            },
+            TestCase {
+                input: (
+                    "Sensor at x=5, y=5: closest beacon is at x=5, y=15"
+                        .parse()
+                        .unwrap(),
+                    10,
+                ),
+                output: None,
+            },
+        ];
+        for tc in test_cases {
+            assert_eq!(solve(&tc.input.0, tc.input.1), tc.output);
+        }
+    }
+}
@@ -0,0 +1,288 @@
+use std::{
+    collections::{HashMap, HashSet},
+    error::Error,
+    fmt::Display,
+    num::ParseIntError,
+    str::FromStr,
+};
+
+pub(crate) const START: &str = "AA";
+pub(crate) const MAX_USEFUL_VALVES: usize = 24;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct Valve {
+    pub(crate) name: String,
+    pub(crate) flow_rate: u32,
+    pub(crate) tunnels: Vec<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) enum ParseValveError {
+    InvalidFormat(String),
+    ParseFlowRate(ParseIntError),
+}
+
+impl Display for ParseValveError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match *self {
+            Self::InvalidFormat(ref valve) => {
+                write!(f, "invalid format for valve: {}", valve)
+            }
+            Self::ParseFlowRate(ref err) => {
+                write!(f, "failed to parse flow rate: {}", err)
+            }
+        }
+    }
+}
+
+impl Error for ParseValveError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match *self {
+            Self::InvalidFormat(_) => None,
+            Self::ParseFlowRate(ref err) => Some(err),
+        }
+    }
+}
+
+impl FromStr for Valve {
+    type Err = ParseValveError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let invalid = || ParseValveError::InvalidFormat(s.to_string());
+        let (name, rest) = s
+            .strip_prefix("Valve ")
+            .and_then(|valve| valve.split_once(" has flow rate="))
+            .ok_or_else(invalid)?;
+        let (flow_rate, tunnels) = rest
+            .split_once("; tunnels lead to valves ")
+            .or_else(|| rest.split_once("; tunnel leads to valve "))
+            .ok_or_else(invalid)?;
+        Ok(Self {
+            name: name.to_string(),
+            flow_rate: flow_rate
+                .parse()
+                .map_err(ParseValveError::ParseFlowRate)?,
+            tunnels: tunnels.split(", ").map(str::to_string).collect(),
+        })
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) enum ParseInputError {
+    Valve {
+        line: usize,
+        source: ParseValveError,
+    },
+    DuplicateValve(String),
+    UnknownValve {
+        valve: String,
+        tunnel: String,
+    },
+    MissingStart,
+    TooManyUsefulValves(usize),
+}
+
+impl Display for ParseInputError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match *self {
+            Self::Valve { line, ref source } => {
+                write!(f, "failed to parse valve on line {}: {}", line, source)
+            }
+            Self::DuplicateValve(ref valve) => {
+                write!(f, "valve {} is defined more than once", valve)
+            }
+            Self::UnknownValve {
+                ref valve,
+                ref tunnel,
+            } => write!(
+                f,
+                "valve {} has a tunnel to unknown valve {}",
+                valve, tunnel
+            ),
+            Self::MissingStart => write!(f, "missing start valve {}", START),
+            Self::TooManyUsefulValves(count) => write!(
+                f,
+                "{} valves have a non-zero flow rate, at most {} are supported",
+                count, MAX_USEFUL_VALVES
+            ),
+        }
+    }
+}
+
+impl Error for ParseInputError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match *self {
+            Self::Valve { ref source, .. } => Some(source),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct Input {
+    pub(crate) valves: Vec<Valve>,
+}
+
+impl Input {
+    pub(crate) fn indices(&self) -> HashMap<&str, usize> {
+        self.valves
+            .iter()
+            .enumerate()
+            .map(|(index, valve)| (valve.name.as_str(), index))
+            .collect()
+    }
+}
+
+impl FromStr for Input {
+    type Err = ParseInputError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let valves = s
+            .lines()
+            .enumerate()
+            .map(|(index, line)| {
+                line.parse().map_err(|source| ParseInputError::Valve {
+                    line: index + 1,
+                    source,
+                })
+            })
+            .collect::<Result<Vec<Valve>, _>>()?;
+        let mut names = HashSet::new();
+        for valve in &valves {
+            if !names.insert(valve.name.as_str()) {
+                return Err(ParseInputError::DuplicateValve(
+                    valve.name.clone(),
+                ));
+            }
+        }
+        let input = Self { valves };
+        let indices = input.indices();
+        if !indices.contains_key(START) {
+            return Err(ParseInputError::MissingStart);
+        }
+        for valve in &input.valves {
+            for tunnel in &valve.tunnels {
+                if !indices.contains_key(tunnel.as_str()) {
+                    return Err(ParseInputError::UnknownValve {
+                        valve: valve.name.clone(),
+                        tunnel: tunnel.clone(),
+                    });
+                }
+            }
+        }
+        let useful = input
+            .valves
+            .iter()
+            .filter(|valve| valve.flow_rate > 0)
+            .count();
+        if useful > MAX_USEFUL_VALVES {
+            return Err(ParseInputError::TooManyUsefulValves(useful));
+        }
+        Ok(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn valve_from_str() {
+        struct TestCase {
+            input: &'static str,
+            output: Result<Valve, ParseValveError>,
+        }
+        let test_cases = [
+            TestCase {
+                input: "Valve AA has flow rate=0; tunnels lead to valves DD, \
+                        II, BB",
+                output: Ok(Valve {
+                    name: "AA".to_string(),
+                    flow_rate: 0,
+                    tunnels: vec![
+                        "DD".to_string(),
+                        "II".to_string(),
+                        "BB".to_string(),
+                    ],
+                }),
+            },
+            TestCase {
+                input: "Valve HH has flow rate=22; tunnel leads to valve GG",
+                output: Ok(Valve {
+                    name: "HH".to_string(),
+                    flow_rate: 22,
+                    tunnels: vec!["GG".to_string()],
+                }),
+            },
+            TestCase {
+                input: "Valve HH has flow rate=22; tunnels lead to valve GG",
+                output: Err(ParseValveError::InvalidFormat(
+                    "Valve HH has flow rate=22; tunnels lead to valve GG"
+                        .to_string(),
+                )),
+            },
+        ];
+        for tc in test_cases {
+            assert_eq!(tc.input.parse::<Valve>(), tc.output);
+        }
+    }
+
+    #[test]
+    fn input_from_str_error() {
+        struct TestCase {
+            input: &'static str,
+            output: &'static str,
+        }
+        let test_cases = [
+            TestCase {
+                input: "Valve AA has flow rate=x; tunnel leads to valve AA",
+                output: "failed to parse valve on line 1: failed to parse \
+                         flow rate: invalid digit found in string",
+            },
+            TestCase {
+                input: "Valve BB has flow rate=1; tunnel leads to valve BB",
+                output: "missing start valve AA",
+            },
+            TestCase {
+                input: "Valve AA has flow rate=0; tunnel leads to valve ZZ",
+                output: "valve AA has a tunnel to unknown valve ZZ",
+            },
+            TestCase {
+                input: "Valve AA has flow rate=0; tunnel leads to valve BB\n\
+                        Valve BB has flow rate=1; tunnel leads to valve AA\n\
+                        Valve AA has flow rate=2; tunnel leads to valve BB",
+                output: "valve AA is defined more than once",
+            },
+        ];
+        for tc in test_cases {
+            let err = tc.input.parse::<Input>().unwrap_err();
+            assert_eq!(err.to_string(), tc.output);
+        }
+    }
+
+    #[test]
+    fn input_from_str_too_many_useful_valves() {
+        let names = (0..=MAX_USEFUL_VALVES)
+            .map(|index| format!("V{}", index))
+            .chain([START.to_string()])
+            .collect::<Vec<_>>();
+        let input = names
+            .iter()
+            .map(|name| {
+                format!(
+                    "Valve {} has flow rate={}; tunnel leads to valve {}",
+                    name,
+                    (name != START) as u32,
+                    START
+                )
+            })
+            .collect::<Vec<_>>()
+            .join("\n");
+        assert_eq!(
+            input.parse::<Input>(),
+            Err(ParseInputError::TooManyUsefulValves(MAX_USEFUL_VALVES + 1))
+        );
+        let input = input.split_once('\n').unwrap().1;
+        assert!(input.parse::<Input>().is_ok());
+    }
+}
@@ -0,0 +1,150 @@
+use crate::input::{Input, START};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct Network {
+    flow_rates: Vec<u32>,
+    distances: Vec<Vec<u32>>,
+    start_distances: Vec<u32>,
+}
+
+impl From<&Input> for Network {
+    fn from(input: &Input) -> Self {
+        let valves = &input.valves;
+        let indices = input.indices();
+        let count = valves.len();
+        let mut distances = vec![vec![u32::MAX; count]; count];
+        for (i, valve) in valves.iter().enumerate() {
+            distances[i][i] = 0;
+            for tunnel in &valve.tunnels {
+                distances[i][indices[tunnel.as_str()]] = 1;
+            }
+        }
+        for k in 0..count {
+            for i in 0..count {
+                for j in 0..count {
+                    let through =
+                        distances[i][k].saturating_add(distances[k][j]);
+                    if through < distances[i][j] {
+                        distances[i][j] = through;
+                    }
+                }
+            }
+        }
+        let useful = (0..count)
+            .filter(|&i| valves[i].flow_rate > 0)
+            .collect::<Vec<_>>();
+        let start = indices[START];
+        Self {
+            flow_rates: useful.iter().map(|&i| valves[i].flow_rate).collect(),
+            distances: useful
+                .iter()
+                .map(|&i| useful.iter().map(|&j| distances[i][j]).collect())
+                .collect(),
+            start_distances: useful
+                .iter()
+                .map(|&i| distances[start][i])
+                .collect(),
+        }
+    }
+}
+
+impl Network {
+    fn visit(
+        &self,
+        valve: usize,
+        minutes: u32,
+        opened: usize,
+        pressure: u32,
+        best: &mut [u32],
+    ) {
+        best[opened] = best[opened].max(pressure);
+        for next in 0..self.flow_rates.len() {
+            if opened & (1 << next) != 0 {
+                continue;
+            }
+            let cost = self.distances[valve][next].saturating_add(1);
+            if cost >= minutes {
+                continue;
+            }
+            let remaining = minutes - cost;
+            self.visit(
+                next,
+                remaining,
+                opened | (1 << next),
+                pressure + remaining * self.flow_rates[next],
+                best,
+            );
+        }
+    }
+
+    pub(crate) fn best_per_set(&self, minutes: u32) -> Vec<u32> {
+        let mut best = vec![0; 1 << self.flow_rates.len()];
+        for first in 0..self.flow_rates.len() {
+            let cost = self.start_distances[first].saturating_add(1);
+            if cost >= minutes {
+                continue;
+            }
+            let remaining = minutes - cost;
+            self.visit(
+                first,
+                remaining,
+                1 << first,
+                remaining * self.flow_rates[first],
+                &mut best,
+            );
+        }
+        best
+    }
+}
+
+pub(crate) fn best_within_subsets(mut best: Vec<u32>) -> Vec<u32> {
+    let bits = best.len().trailing_zeros();
+    for bit in 0..bits {
+        for mask in 0..best.len() {
+            if mask & (1 << bit) != 0 {
+                best[mask] = best[mask].max(best[mask ^ (1 << bit)]);
+            }
+        }
+    }
+    best
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn network_from_input() {
+        let input = include_str!("input.txt").parse::<Input>().unwrap();
+        let network = Network::from(&input);
+        assert_eq!(network.flow_rates, vec![13, 2, 20, 3, 22, 21]);
+        assert_eq!(network.start_distances, vec![1, 2, 1, 2, 5, 2]);
+        assert_eq!(network.distances[0], vec![0, 1, 2, 3, 6, 3]);
+        assert_eq!(network.distances[4], vec![6, 5, 4, 3, 0, 7]);
+    }
+
+    #[test]
+    fn test_best_within_subsets() {
+        struct TestCase {
+            input: Vec<u32>,
+            output: Vec<u32>,
+        }
+        let test_cases = [
+            TestCase {
+                input: vec![0, 5, 3, 4],
+                output: vec![0, 5, 3, 5],
+            },
+            TestCase {
+                input: vec![0, 1, 0, 0, 2, 0, 0, 0],
+                output: vec![0, 1, 0, 1, 2, 2, 2, 2],
+            },
+            TestCase {
+                input: vec![7],
+                output: vec![7],
+            },
+        ];
+        for tc in test_cases {
+            assert_eq!(best_within_subsets(tc.input), tc.output);
+        }
+    }
+}
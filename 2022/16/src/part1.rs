@@ -0,0 +1,47 @@
+use crate::{input::Input, network::Network, output::Output};
+
+const MINUTES: u32 = 30;
+
+pub(crate) fn solve(input: &Input) -> Output {
+    let best = Network::from(input).best_per_set(MINUTES);
+    Output {
+        pressure: best.into_iter().max().unwrap_or(0),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_solve() {
+        struct TestCase {
+            input: Input,
+            output: Output,
+        }
+        let test_cases = [
+            TestCase {
+                input: include_str!("input.txt").parse().unwrap(),
+                output: Output { pressure: 1651 },
+            },
+            TestCase {
+                input: "Valve AA has flow rate=0; tunnel leads to valve AA"
+                    .parse()
+                    .unwrap(),
+                output: Output { pressure: 0 },
+            },
+            TestCase {
+                input: "Valve AA has flow rate=10; tunnel leads to valve BB\n\
+                        Valve BB has flow rate=1; tunnel leads to valve AA"
+                    .parse()
+                    .unwrap(),
+                output: Output {
+                    pressure: 29 * 10 + 27,
+                },
+            },
+        ];
+        for tc in test_cases {
+            assert_eq!(solve(&tc.input), tc.output);
+        }
+    }
+}
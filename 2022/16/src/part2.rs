@@ -0,0 +1,51 @@
+use crate::{
+    input::Input,
+    network::{Network, best_within_subsets},
+    output::Output,
+};
+
+const MINUTES: u32 = 26;
+
+pub(crate) fn solve(input: &Input) -> Output {
+    let best = best_within_subsets(Network::from(input).best_per_set(MINUTES));
+    let all = best.len() - 1;
+    Output {
+        pressure: (0..best.len())
+            .map(|mine| best[mine] + best[all ^ mine])
+            .max()
+            .unwrap_or(0),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_solve() {
+        struct TestCase {
+            input: Input,
+            output: Output,
+        }
+        let test_cases = [
+            TestCase {
+                input: include_str!("input.txt").parse().unwrap(),
+                output: Output { pressure: 1707 },
+            },
+            TestCase {
+                input:
+                    "Valve AA has flow rate=0; tunnels lead to valves BB, CC\n\
+                        Valve BB has flow rate=5; tunnel leads to valve AA\n\
+                        Valve CC has flow rate=7; tunnel leads to valve AA"
+                        .parse()
+                        .unwrap(),
+                output: Output {
+                    pressure: 24 * 5 + 24 * 7,
+                },
+            },
+        ];
+        for tc in test_cases {
+            assert_eq!(solve(&tc.input), tc.output);
+        }
+    }
+}
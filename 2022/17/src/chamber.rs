@@ -0,0 +1,206 @@
+use std::{collections::HashMap, fmt::Display};
+
+use crate::input::Jet;
+
+const WIDTH: usize = 7;
+const LEFT_WALL: u8 = 1 << (WIDTH - 1);
+const RIGHT_WALL: u8 = 1;
+const SPAWN_GAP: usize = 3;
+
+const ROCKS: [&[u8]; 5] = [
+    &[0b0011110],
+    &[0b0001000, 0b0011100, 0b0001000],
+    &[0b0011100, 0b0000100, 0b0000100],
+    &[0b0010000, 0b0010000, 0b0010000, 0b0010000],
+    &[0b0011000, 0b0011000],
+];
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct Chamber<'a> {
+    jets: &'a [Jet],
+    rows: Vec<u8>,
+    rock_index: usize,
+    jet_index: usize,
+}
+
+impl<'a> Chamber<'a> {
+    pub(crate) fn new(jets: &'a [Jet]) -> Self {
+        Self {
+            jets,
+            rows: Vec::new(),
+            rock_index: 0,
+            jet_index: 0,
+        }
+    }
+
+    pub(crate) fn height(&self) -> usize {
+        self.rows.len()
+    }
+
+    fn collides(&self, rock: &[u8], bottom: usize) -> bool {
+        rock.iter().enumerate().any(|(dy, &row)| {
+            self.rows
+                .get(bottom + dy)
+                .is_some_and(|&filled| filled & row != 0)
+        })
+    }
+
+    pub(crate) fn drop_rock(&mut self) {
+        let mut rock = ROCKS[self.rock_index].to_vec();
+        self.rock_index = (self.rock_index + 1) % ROCKS.len();
+        let mut bottom = self.height() + SPAWN_GAP;
+        loop {
+            let jet = self.jets[self.jet_index];
+            self.jet_index = (self.jet_index + 1) % self.jets.len();
+            let pushed = match jet {
+                Jet::Left if rock.iter().all(|&row| row & LEFT_WALL == 0) => {
+                    Some(rock.iter().map(|&row| row << 1).collect::<Vec<_>>())
+                }
+                Jet::Right if rock.iter().all(|&row| row & RIGHT_WALL == 0) => {
+                    Some(rock.iter().map(|&row| row >> 1).collect::<Vec<_>>())
+                }
+                _ => None,
+            };
+            if let Some(pushed) =
+                pushed.filter(|pushed| !self.collides(pushed, bottom))
+            {
+                rock = pushed;
+            }
+            if bottom == 0 || self.collides(&rock, bottom - 1) {
+                break;
+            }
+            bottom -= 1;
+        }
+        for (dy, row) in rock.into_iter().enumerate() {
+            match self.rows.get_mut(bottom + dy) {
+                Some(filled) => *filled |= row,
+                None => self.rows.push(row),
+            }
+        }
+    }
+
+    fn profile(&self) -> [usize; WIDTH] {
+        let mut profile = [self.height(); WIDTH];
+        for (column, depth) in profile.iter_mut().enumerate() {
+            let bit = LEFT_WALL >> column;
+            if let Some(top) = self.rows.iter().rposition(|&row| row & bit != 0)
+            {
+                *depth = self.height() - 1 - top;
+            }
+        }
+        profile
+    }
+}
+
+impl Display for Chamber<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for row in self.rows.iter().rev() {
+            write!(f, "|")?;
+            for column in 0..WIDTH {
+                let filled = row & (LEFT_WALL >> column) != 0;
+                write!(f, "{}", if filled { '#' } else { '.' })?;
+            }
+            writeln!(f, "|")?;
+        }
+        write!(f, "+{}+", "-".repeat(WIDTH))
+    }
+}
+
+pub(crate) fn tower_height(jets: &[Jet], rocks: u64) -> u64 {
+    let mut chamber = Chamber::new(jets);
+    let mut seen = HashMap::new();
+    let mut skipped = 0;
+    let mut dropped = 0;
+    while dropped < rocks {
+        chamber.drop_rock();
+        dropped += 1;
+        if skipped > 0 {
+            continue;
+        }
+        let state = (chamber.rock_index, chamber.jet_index, chamber.profile());
+        let height = chamber.height() as u64;
+        if let Some((previous_dropped, previous_height)) =
+            seen.insert(state, (dropped, height))
+        {
+            let period = dropped - previous_dropped;
+            let cycles = (rocks - dropped) / period;
+            skipped = cycles * (height - previous_height);
+            dropped += cycles * period;
+        }
+    }
+    chamber.height() as u64 + skipped
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::input::Input;
+
+    #[test]
+    fn chamber_display() {
+        let input = include_str!("input.txt").parse::<Input>().unwrap();
+        let mut chamber = Chamber::new(&input.jets);
+        for _ in 0..3 {
+            chamber.drop_rock();
+        }
+        assert_eq!(
+            chamber.to_string(),
+            "|..#....|\n\
+             |..#....|\n\
+             |####...|\n\
+             |..###..|\n\
+             |...#...|\n\
+             |..####.|\n\
+             +-------+"
+        );
+    }
+
+    #[test]
+    fn test_tower_height() {
+        struct TestCase {
+            input: u64,
+            output: u64,
+        }
+        let test_cases = [
+            TestCase {
+                input: 0,
+                output: 0,
+            },
+            TestCase {
+                input: 1,
+                output: 1,
+            },
+            TestCase {
+                input: 10,
+                output: 17,
+            },
+            TestCase {
+                input: 2022,
+                output: 3068,
+            },
+            TestCase {
+                input: 1_000_000_000_000,
+                output: 1514285714288,
+            },
+        ];
+        let input = include_str!("input.txt").parse::<Input>().unwrap();
+        for tc in test_cases {
+            assert_eq!(tower_height(&input.jets, tc.input), tc.output);
+        }
+    }
+
+    #[test]
+    fn tower_height_matches_simulation() {
+        let input = include_str!("input.txt").parse::<Input>().unwrap();
+        let mut chamber = Chamber::new(&input.jets);
+        for rocks in 1..=5000u64 {
+            chamber.drop_rock();
+            if rocks.is_multiple_of(997) {
+                assert_eq!(
+                    tower_height(&input.jets, rocks),
+                    chamber.height() as u64
+                );
+            }
+        }
+    }
+}
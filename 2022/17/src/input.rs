@@ -0,0 +1,92 @@
+use std::{error::Error, fmt::Display, str::FromStr};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum Jet {
+    Left,
+    Right,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) enum ParseInputError {
+    Empty,
+    InvalidCharacter { character: char, column: usize },
+}
+
+impl Display for ParseInputError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match *self {
+            Self::Empty => {
+                write!(f, "cannot parse jet pattern from empty string")
+            }
+            Self::InvalidCharacter { character, column } => {
+                write!(f, "invalid jet {:?} at column {}", character, column)
+            }
+        }
+    }
+}
+
+impl Error for ParseInputError {}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct Input {
+    pub(crate) jets: Vec<Jet>,
+}
+
+impl FromStr for Input {
+    type Err = ParseInputError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let jets = s
+            .trim_end()
+            .chars()
+            .enumerate()
+            .map(|(index, character)| match character {
+                '<' => Ok(Jet::Left),
+                '>' => Ok(Jet::Right),
+                _ => Err(ParseInputError::InvalidCharacter {
+                    character,
+                    column: index + 1,
+                }),
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        if jets.is_empty() {
+            return Err(ParseInputError::Empty);
+        }
+        Ok(Self { jets })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn input_from_str() {
+        struct TestCase {
+            input: &'static str,
+            output: Result<Input, ParseInputError>,
+        }
+        let test_cases = [
+            TestCase {
+                input: "><<\n",
+                output: Ok(Input {
+                    jets: vec![Jet::Right, Jet::Left, Jet::Left],
+                }),
+            },
+            TestCase {
+                input: "\n",
+                output: Err(ParseInputError::Empty),
+            },
+            TestCase {
+                input: "<<v>",
+                output: Err(ParseInputError::InvalidCharacter {
+                    character: 'v',
+                    column: 3,
+                }),
+            },
+        ];
+        for tc in test_cases {
+            assert_eq!(tc.input.parse::<Input>(), tc.output);
+        }
+    }
+}
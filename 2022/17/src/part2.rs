@@ -0,0 +1,31 @@
+use crate::{chamber::tower_height, input::Input, output::Output};
+
+const ROCKS: u64 = 1_000_000_000_000;
+
+pub(crate) fn solve(input: &Input) -> Output {
+    Output {
+        height: tower_height(&input.jets, ROCKS),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_solve() {
+        struct TestCase {
+            input: Input,
+            output: Output,
+        }
+        let test_cases = [TestCase {
+            input: include_str!("input.txt").parse().unwrap(),
+            output: Output {
+                height: 1514285714288,
+            },
+        }];
+        for tc in test_cases {
+            assert_eq!(solve(&tc.input), tc.output);
+        }
+    }
+}
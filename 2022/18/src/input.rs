@@ -0,0 +1,136 @@
+use std::{error::Error, fmt::Display, num::ParseIntError, str::FromStr};
+
+use voxel::Voxel;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) enum ParseCubeError {
+    InvalidFormat(String),
+    ParseCoordinate(ParseIntError),
+}
+
+impl Display for ParseCubeError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match *self {
+            Self::InvalidFormat(ref cube) => {
+                write!(f, "invalid format for cube: {}", cube)
+            }
+            Self::ParseCoordinate(ref err) => {
+                write!(f, "failed to parse coordinate: {}", err)
+            }
+        }
+    }
+}
+
+impl Error for ParseCubeError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match *self {
+            Self::InvalidFormat(_) => None,
+            Self::ParseCoordinate(ref err) => Some(err),
+        }
+    }
+}
+
+fn parse_cube(s: &str) -> Result<Voxel, ParseCubeError> {
+    let coordinates = s
+        .split(',')
+        .map(|coordinate| {
+            coordinate.parse().map_err(ParseCubeError::ParseCoordinate)
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+    match coordinates[..] {
+        [x, y, z] => Ok(Voxel { x, y, z }),
+        _ => Err(ParseCubeError::InvalidFormat(s.to_string())),
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct ParseInputError {
+    pub(crate) line: usize,
+    pub(crate) source: ParseCubeError,
+}
+
+impl Display for ParseInputError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "failed to parse cube on line {}: {}",
+            self.line, self.source
+        )
+    }
+}
+
+impl Error for ParseInputError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        Some(&self.source)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct Input {
+    pub(crate) cubes: Vec<Voxel>,
+}
+
+impl FromStr for Input {
+    type Err = ParseInputError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let cubes = s
+            .lines()
+            .enumerate()
+            .map(|(index, line)| {
+                parse_cube(line).map_err(|source| ParseInputError {
+                    line: index + 1,
+                    source,
+                })
+            })
+            .collect::<Result<_, _>>()?;
+        Ok(Self { cubes })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn input_from_str() {
+        struct TestCase {
+            input: &'static str,
+            output: Result<Input, String>,
+        }
+        let test_cases = [
+            TestCase {
+                input: "2,2,2\n-1,0,13",
+                output: Ok(Input {
+                    cubes: vec![
+                        Voxel { x: 2, y: 2, z: 2 },
+                        Voxel { x: -1, y: 0, z: 13 },
+                    ],
+                }),
+            },
+            TestCase {
+                input: "2,2,2\n1,2",
+                output: Err("failed to parse cube on line 2: invalid format \
+                             for cube: 1,2"
+                    .to_string()),
+            },
+            TestCase {
+                input: "2,2,2,2",
+                output: Err("failed to parse cube on line 1: invalid format \
+                             for cube: 2,2,2,2"
+                    .to_string()),
+            },
+            TestCase {
+                input: "2,x,2",
+                output: Err("failed to parse cube on line 1: failed to parse \
+                             coordinate: invalid digit found in string"
+                    .to_string()),
+            },
+        ];
+        for tc in test_cases {
+            let result =
+                tc.input.parse::<Input>().map_err(|err| err.to_string());
+            assert_eq!(result, tc.output);
+        }
+    }
+}
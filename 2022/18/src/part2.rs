@@ -0,0 +1,36 @@
+use voxel::VoxelSet;
+
+use crate::{input::Input, output::Output};
+
+pub(crate) fn solve(input: &Input) -> Output {
+    let voxels = input.cubes.iter().copied().collect::<VoxelSet>();
+    Output {
+        area: voxels.exterior_surface_area(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_solve() {
+        struct TestCase {
+            input: Input,
+            output: Output,
+        }
+        let test_cases = [
+            TestCase {
+                input: include_str!("input.txt").parse().unwrap(),
+                output: Output { area: 58 },
+            },
+            TestCase {
+                input: "1,1,1".parse().unwrap(),
+                output: Output { area: 6 },
+            },
+        ];
+        for tc in test_cases {
+            assert_eq!(solve(&tc.input), tc.output);
+        }
+    }
+}
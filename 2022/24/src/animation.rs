@@ -0,0 +1,94 @@
+use std::fmt::Display;
+
+use crate::{blizzards::BlizzardMap, input::Valley};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct Frame {
+    pub(crate) minute: usize,
+    pub(crate) valley: Valley,
+    pub(crate) expedition: (usize, usize),
+}
+
+impl Display for Frame {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Minute {}:", self.minute)?;
+        for y in 0..self.valley.height() {
+            writeln!(f)?;
+            for x in 0..self.valley.width() {
+                if (x, y) == self.expedition {
+                    write!(f, "E")?;
+                } else {
+                    write!(f, "{}", self.valley[(x, y)])?;
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+pub(crate) fn frames<'a>(
+    map: &'a BlizzardMap,
+    path: &'a [(usize, usize)],
+) -> impl Iterator<Item = Frame> + 'a {
+    path.iter().enumerate().map(|(minute, &expedition)| Frame {
+        minute,
+        valley: map.valley(minute),
+        expedition,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{input::Input, part1::find_path};
+
+    use super::*;
+
+    #[test]
+    fn test_frames() {
+        struct TestCase {
+            input: &'static str,
+            expected: Vec<&'static str>,
+        }
+        let test_cases = [TestCase {
+            input: "#.######
+#>>.<^<#
+#.<..<<#
+#>v.><>#
+#<^v^^>#
+######.#",
+            expected: vec![
+                "Minute 0:
+#E######
+#>>.<^<#
+#.<..<<#
+#>v.><>#
+#<^v^^>#
+######.#",
+                "Minute 1:
+#E######
+#.>3.<.#
+#<..<<.#
+#>2.22.#
+#>v..^<#
+######.#",
+                "Minute 2:
+#E######
+#.2>2..#
+#.^22^<#
+#.>2.^>#
+#.>..<.#
+######.#",
+            ],
+        }];
+        for tc in test_cases {
+            let input = tc.input.parse::<Input>().unwrap();
+            let map = BlizzardMap::from(&input.valley);
+            let path = find_path(&map, (1, 0), (6, 5), 0).unwrap();
+            let result = frames(&map, &path)
+                .take(tc.expected.len())
+                .map(|frame| frame.to_string())
+                .collect::<Vec<_>>();
+            assert_eq!(result, tc.expected);
+        }
+    }
+}
@@ -0,0 +1,419 @@
+use crate::input::{Blizzard, Cell, Valley};
+
+const BLIZZARDS: [Blizzard; 4] = [
+    Blizzard::Up,
+    Blizzard::Right,
+    Blizzard::Down,
+    Blizzard::Left,
+];
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct BitSet(Vec<u64>);
+
+impl BitSet {
+    pub(crate) fn new(len: usize) -> Self {
+        Self(vec![0; len.div_ceil(64)])
+    }
+
+    pub(crate) fn insert(&mut self, index: usize) {
+        self.0[index / 64] |= 1 << (index % 64);
+    }
+
+    pub(crate) fn contains(&self, index: usize) -> bool {
+        self.0[index / 64] & (1 << (index % 64)) != 0
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct BlizzardMap {
+    width: usize,
+    height: usize,
+    entrance: (usize, usize),
+    exit: (usize, usize),
+    walls: Vec<BitSet>,
+    up: Vec<BitSet>,
+    right: Vec<BitSet>,
+    down: Vec<BitSet>,
+    left: Vec<BitSet>,
+}
+
+impl BlizzardMap {
+    pub(crate) fn width(&self) -> usize {
+        self.width
+    }
+
+    pub(crate) fn height(&self) -> usize {
+        self.height
+    }
+
+    pub(crate) fn entrance(&self) -> (usize, usize) {
+        self.entrance
+    }
+
+    pub(crate) fn exit(&self) -> (usize, usize) {
+        self.exit
+    }
+
+    fn inner_width(&self) -> usize {
+        self.width - 2
+    }
+
+    fn inner_height(&self) -> usize {
+        self.height - 2
+    }
+
+    pub(crate) fn period(&self) -> usize {
+        let (mut a, mut b) = (self.inner_width(), self.inner_height());
+        while b != 0 {
+            (a, b) = (b, a % b);
+        }
+        self.inner_width() / a * self.inner_height()
+    }
+
+    fn is_inner(&self, (x, y): (usize, usize)) -> bool {
+        (1..self.width - 1).contains(&x) && (1..self.height - 1).contains(&y)
+    }
+
+    fn has_blizzard(
+        &self,
+        (x, y): (usize, usize),
+        minute: usize,
+        blizzard: Blizzard,
+    ) -> bool {
+        let (x, y) = (x - 1, y - 1);
+        let (width, height) = (self.inner_width(), self.inner_height());
+        let (dx, dy) = (minute % width, minute % height);
+        match blizzard {
+            Blizzard::Up => self.up[x].contains((y + dy) % height),
+            Blizzard::Right => self.right[y].contains((x + width - dx) % width),
+            Blizzard::Down => self.down[x].contains((y + height - dy) % height),
+            Blizzard::Left => self.left[y].contains((x + dx) % width),
+        }
+    }
+
+    pub(crate) fn blizzards(
+        &self,
+        position: (usize, usize),
+        minute: usize,
+    ) -> impl Iterator<Item = Blizzard> {
+        let inner = self.is_inner(position);
+        BLIZZARDS.into_iter().filter(move |&blizzard| {
+            inner && self.has_blizzard(position, minute, blizzard)
+        })
+    }
+
+    pub(crate) fn valley(&self, minute: usize) -> Valley {
+        Valley {
+            cells: (0..self.height)
+                .map(|y| {
+                    (0..self.width)
+                        .map(|x| {
+                            if self.walls[y].contains(x) {
+                                Cell::Wall
+                            } else {
+                                Cell::Blizzards(
+                                    self.blizzards((x, y), minute).collect(),
+                                )
+                            }
+                        })
+                        .collect()
+                })
+                .collect(),
+            entrance: self.entrance,
+            exit: self.exit,
+        }
+    }
+
+    pub(crate) fn is_free(
+        &self,
+        (x, y): (usize, usize),
+        minute: usize,
+    ) -> bool {
+        if x >= self.width || y >= self.height || self.walls[y].contains(x) {
+            return false;
+        }
+        !self.is_inner((x, y))
+            || !BLIZZARDS
+                .into_iter()
+                .any(|blizzard| self.has_blizzard((x, y), minute, blizzard))
+    }
+}
+
+impl From<&Valley> for BlizzardMap {
+    fn from(valley: &Valley) -> Self {
+        let (width, height) = (valley.width(), valley.height());
+        let (inner_width, inner_height) = (width - 2, height - 2);
+        let mut map = Self {
+            width,
+            height,
+            entrance: valley.entrance,
+            exit: valley.exit,
+            walls: vec![BitSet::new(width); height],
+            up: vec![BitSet::new(inner_height); inner_width],
+            right: vec![BitSet::new(inner_width); inner_height],
+            down: vec![BitSet::new(inner_height); inner_width],
+            left: vec![BitSet::new(inner_width); inner_height],
+        };
+        for y in 0..height {
+            for x in 0..width {
+                let blizzards = match valley[(x, y)] {
+                    Cell::Wall => {
+                        map.walls[y].insert(x);
+                        continue;
+                    }
+                    Cell::Blizzards(ref blizzards) => blizzards,
+                };
+                if !map.is_inner((x, y)) {
+                    continue;
+                }
+                for blizzard in blizzards {
+                    match blizzard {
+                        Blizzard::Up => map.up[x - 1].insert(y - 1),
+                        Blizzard::Right => map.right[y - 1].insert(x - 1),
+                        Blizzard::Down => map.down[x - 1].insert(y - 1),
+                        Blizzard::Left => map.left[y - 1].insert(x - 1),
+                    }
+                }
+            }
+        }
+        map
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::input::Input;
+
+    use super::*;
+
+    #[test]
+    fn test_blizzard_map_is_free() {
+        struct TestCase {
+            input: ((usize, usize), usize),
+            expected: bool,
+        }
+        let input = "#.#####
+#.....#
+#>....#
+#.....#
+#...v.#
+#.....#
+#####.#"
+            .parse::<Input>()
+            .unwrap();
+        let map = BlizzardMap::from(&input.valley);
+        let test_cases = [
+            TestCase {
+                input: ((0, 0), 0),
+                expected: false,
+            },
+            TestCase {
+                input: ((1, 0), 0),
+                expected: true,
+            },
+            TestCase {
+                input: ((1, 2), 0),
+                expected: false,
+            },
+            TestCase {
+                input: ((2, 2), 1),
+                expected: false,
+            },
+            TestCase {
+                input: ((1, 2), 1),
+                expected: true,
+            },
+            TestCase {
+                input: ((1, 2), 5),
+                expected: false,
+            },
+            TestCase {
+                input: ((4, 4), 0),
+                expected: false,
+            },
+            TestCase {
+                input: ((4, 5), 1),
+                expected: false,
+            },
+            TestCase {
+                input: ((4, 1), 2),
+                expected: false,
+            },
+            TestCase {
+                input: ((4, 1), 3),
+                expected: true,
+            },
+            TestCase {
+                input: ((5, 6), 3),
+                expected: true,
+            },
+            TestCase {
+                input: ((7, 2), 0),
+                expected: false,
+            },
+        ];
+        for tc in test_cases {
+            let result = map.is_free(tc.input.0, tc.input.1);
+            assert_eq!(result, tc.expected);
+        }
+    }
+
+    #[test]
+    fn test_blizzard_map_valley() {
+        struct TestCase {
+            input: (&'static str, usize),
+            expected: &'static str,
+        }
+        let test_cases = [
+            TestCase {
+                input: (
+                    "#.######
+#>>.<^<#
+#.<..<<#
+#>v.><>#
+#<^v^^>#
+######.#",
+                    0,
+                ),
+                expected: "#.######
+#>>.<^<#
+#.<..<<#
+#>v.><>#
+#<^v^^>#
+######.#",
+            },
+            TestCase {
+                input: (
+                    "#.######
+#>>.<^<#
+#.<..<<#
+#>v.><>#
+#<^v^^>#
+######.#",
+                    1,
+                ),
+                expected: "#.######
+#.>3.<.#
+#<..<<.#
+#>2.22.#
+#>v..^<#
+######.#",
+            },
+        ];
+        for tc in test_cases {
+            let input = tc.input.0.parse::<Input>().unwrap();
+            let map = BlizzardMap::from(&input.valley);
+            let result = map.valley(tc.input.1).to_string();
+            assert_eq!(result, tc.expected);
+        }
+    }
+
+    #[test]
+    fn test_blizzard_map_period() {
+        struct TestCase {
+            input: &'static str,
+            expected: usize,
+        }
+        let test_cases = [
+            TestCase {
+                input: "#.#####
+#.....#
+#>....#
+#.....#
+#...v.#
+#.....#
+#####.#",
+                expected: 5,
+            },
+            TestCase {
+                input: "#.######
+#>>.<^<#
+#.<..<<#
+#>v.><>#
+#<^v^^>#
+######.#",
+                expected: 12,
+            },
+        ];
+        for tc in test_cases {
+            let input = tc.input.parse::<Input>().unwrap();
+            let result = BlizzardMap::from(&input.valley).period();
+            assert_eq!(result, tc.expected);
+        }
+    }
+
+    fn step(valley: &Valley) -> Valley {
+        let (width, height) = (valley.width(), valley.height());
+        let mut cells = valley
+            .cells
+            .iter()
+            .map(|row| {
+                row.iter()
+                    .map(|cell| match cell {
+                        Cell::Wall => Cell::Wall,
+                        Cell::Blizzards(_) => Cell::Blizzards(Vec::new()),
+                    })
+                    .collect::<Vec<_>>()
+            })
+            .collect::<Vec<_>>();
+        for (y, row) in valley.cells.iter().enumerate() {
+            for (x, cell) in row.iter().enumerate() {
+                let Cell::Blizzards(blizzards) = cell else {
+                    continue;
+                };
+                for &blizzard in blizzards {
+                    let (x, y) = match blizzard {
+                        Blizzard::Up if y == 1 => (x, height - 2),
+                        Blizzard::Up => (x, y - 1),
+                        Blizzard::Right if x == width - 2 => (1, y),
+                        Blizzard::Right => (x + 1, y),
+                        Blizzard::Down if y == height - 2 => (x, 1),
+                        Blizzard::Down => (x, y + 1),
+                        Blizzard::Left if x == 1 => (width - 2, y),
+                        Blizzard::Left => (x - 1, y),
+                    };
+                    if let Cell::Blizzards(ref mut blizzards) = cells[y][x] {
+                        blizzards.push(blizzard);
+                    }
+                }
+            }
+        }
+        Valley {
+            cells,
+            entrance: valley.entrance,
+            exit: valley.exit,
+        }
+    }
+
+    #[test]
+    fn test_blizzard_map_matches_simulation() {
+        let input = "#.######
+#>>.<^<#
+#.<..<<#
+#>v.><>#
+#<^v^^>#
+######.#"
+            .parse::<Input>()
+            .unwrap();
+        let map = BlizzardMap::from(&input.valley);
+        let mut valley = input.valley;
+        for minute in 0..=map.period() {
+            for y in 0..valley.height() {
+                for x in 0..valley.width() {
+                    let expected = match valley[(x, y)] {
+                        Cell::Wall => vec![],
+                        Cell::Blizzards(ref blizzards) => {
+                            let mut blizzards = blizzards.clone();
+                            blizzards.sort_by_key(|&blizzard| blizzard as u8);
+                            blizzards
+                        }
+                    };
+                    let result =
+                        map.blizzards((x, y), minute).collect::<Vec<_>>();
+                    assert_eq!(result, expected);
+                }
+            }
+            valley = step(&valley);
+        }
+    }
+}
@@ -1,5 +1,6 @@
 use std::{
-    convert::Infallible,
+    error::Error,
+    fmt::Display,
     ops::{Index, IndexMut},
     str::FromStr,
 };
@@ -12,15 +13,65 @@ pub enum Blizzard {
     Left,
 }
 
+impl Display for Blizzard {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match *self {
+            Self::Up => write!(f, "^"),
+            Self::Right => write!(f, ">"),
+            Self::Down => write!(f, "v"),
+            Self::Left => write!(f, "<"),
+        }
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum Cell {
     Wall,
     Blizzards(Vec<Blizzard>),
 }
 
+impl Display for Cell {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match *self {
+            Self::Wall => write!(f, "#"),
+            Self::Blizzards(ref blizzards) => match blizzards[..] {
+                [] => write!(f, "."),
+                [blizzard] => write!(f, "{}", blizzard),
+                _ => write!(f, "{}", blizzards.len()),
+            },
+        }
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct Valley {
     pub cells: Vec<Vec<Cell>>,
+    pub entrance: (usize, usize),
+    pub exit: (usize, usize),
+}
+
+impl Valley {
+    pub fn width(&self) -> usize {
+        self.cells.first().map_or(0, Vec::len)
+    }
+
+    pub fn height(&self) -> usize {
+        self.cells.len()
+    }
+}
+
+impl Display for Valley {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for y in 0..self.height() {
+            if y > 0 {
+                writeln!(f)?;
+            }
+            for x in 0..self.width() {
+                write!(f, "{}", self[(x, y)])?;
+            }
+        }
+        Ok(())
+    }
 }
 
 impl Index<(usize, usize)> for Valley {
@@ -37,36 +88,207 @@ impl IndexMut<(usize, usize)> for Valley {
     }
 }
 
-#[derive(Debug, Clone, PartialEq, Eq)]
-pub struct Input {
-    pub valley: Valley,
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ParseValleyError {
+    Empty,
+    InvalidCharacter {
+        character: char,
+        position: (usize, usize),
+    },
+    UnevenRow {
+        row: usize,
+        expected: usize,
+        found: usize,
+    },
+    TooSmall {
+        width: usize,
+        height: usize,
+    },
+    MissingWall {
+        position: (usize, usize),
+    },
+    MissingEntrance,
+    MultipleEntrances {
+        positions: Vec<(usize, usize)>,
+    },
+    MissingExit,
+    MultipleExits {
+        positions: Vec<(usize, usize)>,
+    },
+    VerticalBlizzardInGapColumn {
+        position: (usize, usize),
+    },
 }
 
-impl FromStr for Input {
-    type Err = Infallible;
+impl Display for ParseValleyError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match *self {
+            Self::Empty => write!(f, "cannot parse valley from empty string"),
+            Self::InvalidCharacter {
+                character,
+                position: (x, y),
+            } => {
+                write!(f, "invalid character {:?} at ({}, {})", character, x, y)
+            }
+            Self::UnevenRow {
+                row,
+                expected,
+                found,
+            } => write!(
+                f,
+                "row {} has {} cells, expected {}",
+                row, found, expected
+            ),
+            Self::TooSmall { width, height } => {
+                write!(f, "valley of {}x{} is too small", width, height)
+            }
+            Self::MissingWall { position: (x, y) } => {
+                write!(f, "expected wall on the border at ({}, {})", x, y)
+            }
+            Self::MissingEntrance => write!(f, "valley has no entrance"),
+            Self::MultipleEntrances { ref positions } => {
+                write!(f, "valley has multiple entrances at {:?}", positions)
+            }
+            Self::MissingExit => write!(f, "valley has no exit"),
+            Self::MultipleExits { ref positions } => {
+                write!(f, "valley has multiple exits at {:?}", positions)
+            }
+            Self::VerticalBlizzardInGapColumn { position: (x, y) } => write!(
+                f,
+                "vertical blizzard at ({}, {}) in entrance or exit column",
+                x, y
+            ),
+        }
+    }
+}
+
+impl Error for ParseValleyError {}
+
+impl FromStr for Valley {
+    type Err = ParseValleyError;
+
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         let mut cells = Vec::new();
-        for line in s.lines() {
-            let mut temp = Vec::new();
-            for char in line.chars() {
-                temp.push(match char {
+        for (y, line) in s.lines().enumerate() {
+            let mut row = Vec::new();
+            for (x, character) in line.chars().enumerate() {
+                row.push(match character {
                     '#' => Cell::Wall,
                     '.' => Cell::Blizzards(vec![]),
                     '^' => Cell::Blizzards(vec![Blizzard::Up]),
                     '>' => Cell::Blizzards(vec![Blizzard::Right]),
                     'v' => Cell::Blizzards(vec![Blizzard::Down]),
                     '<' => Cell::Blizzards(vec![Blizzard::Left]),
-                    _ => unreachable!(),
+                    _ => {
+                        return Err(ParseValleyError::InvalidCharacter {
+                            character,
+                            position: (x, y),
+                        });
+                    }
+                });
+            }
+            if let Some(first) = cells.first().map(Vec::len)
+                && first != row.len()
+            {
+                return Err(ParseValleyError::UnevenRow {
+                    row: y,
+                    expected: first,
+                    found: row.len(),
                 });
             }
-            cells.push(temp);
+            cells.push(row);
         }
+        let height = cells.len();
+        let width = cells.first().map_or(0, Vec::len);
+        if width == 0 {
+            return Err(ParseValleyError::Empty);
+        }
+        if width < 3 || height < 3 {
+            return Err(ParseValleyError::TooSmall { width, height });
+        }
+
+        for (y, row) in cells.iter().enumerate() {
+            for x in [0, width - 1] {
+                if row[x] != Cell::Wall {
+                    return Err(ParseValleyError::MissingWall {
+                        position: (x, y),
+                    });
+                }
+            }
+        }
+        let gaps = |y: usize| -> Result<Vec<(usize, usize)>, ParseValleyError> {
+            let mut gaps = Vec::new();
+            for (x, cell) in cells[y].iter().enumerate() {
+                match *cell {
+                    Cell::Wall => {}
+                    Cell::Blizzards(ref blizzards) if blizzards.is_empty() => {
+                        gaps.push((x, y))
+                    }
+                    Cell::Blizzards(_) => {
+                        return Err(ParseValleyError::MissingWall {
+                            position: (x, y),
+                        });
+                    }
+                }
+            }
+            Ok(gaps)
+        };
+        let entrance = match gaps(0)?[..] {
+            [] => return Err(ParseValleyError::MissingEntrance),
+            [entrance] => entrance,
+            ref positions => {
+                return Err(ParseValleyError::MultipleEntrances {
+                    positions: positions.to_vec(),
+                });
+            }
+        };
+        let exit = match gaps(height - 1)?[..] {
+            [] => return Err(ParseValleyError::MissingExit),
+            [exit] => exit,
+            ref positions => {
+                return Err(ParseValleyError::MultipleExits {
+                    positions: positions.to_vec(),
+                });
+            }
+        };
+
+        for (y, row) in cells.iter().enumerate() {
+            for x in [entrance.0, exit.0] {
+                if let Cell::Blizzards(ref blizzards) = row[x]
+                    && blizzards
+                        .iter()
+                        .any(|&b| b == Blizzard::Up || b == Blizzard::Down)
+                {
+                    return Err(
+                        ParseValleyError::VerticalBlizzardInGapColumn {
+                            position: (x, y),
+                        },
+                    );
+                }
+            }
+        }
+
         Ok(Self {
-            valley: Valley { cells },
+            cells,
+            entrance,
+            exit,
         })
     }
 }
 
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Input {
+    pub valley: Valley,
+}
+
+impl FromStr for Input {
+    type Err = ParseValleyError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(Self { valley: s.parse()? })
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -148,6 +370,8 @@ mod tests {
                             Cell::Wall,
                         ],
                     ],
+                    entrance: (1, 0),
+                    exit: (6, 5),
                 },
             },
         }];
@@ -155,4 +379,83 @@ mod tests {
             assert_eq!(tc.input.parse::<Input>().unwrap(), tc.output);
         }
     }
+
+    #[test]
+    fn valley_from_str_error() {
+        struct TestCase {
+            input: &'static str,
+            output: ParseValleyError,
+        }
+        let test_cases = [
+            TestCase {
+                input: "",
+                output: ParseValleyError::Empty,
+            },
+            TestCase {
+                input: "#.##\n#.x#\n##.#",
+                output: ParseValleyError::InvalidCharacter {
+                    character: 'x',
+                    position: (2, 1),
+                },
+            },
+            TestCase {
+                input: "#.##\n#..#\n#..\n##.#",
+                output: ParseValleyError::UnevenRow {
+                    row: 2,
+                    expected: 4,
+                    found: 3,
+                },
+            },
+            TestCase {
+                input: "#.#\n#.#",
+                output: ParseValleyError::TooSmall {
+                    width: 3,
+                    height: 2,
+                },
+            },
+            TestCase {
+                input: "#.##\n...#\n##.#",
+                output: ParseValleyError::MissingWall { position: (0, 1) },
+            },
+            TestCase {
+                input: "#>##\n#..#\n##.#",
+                output: ParseValleyError::MissingWall { position: (1, 0) },
+            },
+            TestCase {
+                input: "####\n#..#\n##.#",
+                output: ParseValleyError::MissingEntrance,
+            },
+            TestCase {
+                input: "#..#\n#..#\n##.#",
+                output: ParseValleyError::MultipleEntrances {
+                    positions: vec![(1, 0), (2, 0)],
+                },
+            },
+            TestCase {
+                input: "#.##\n#..#\n####",
+                output: ParseValleyError::MissingExit,
+            },
+            TestCase {
+                input: "#.##\n#..#\n#..#",
+                output: ParseValleyError::MultipleExits {
+                    positions: vec![(1, 2), (2, 2)],
+                },
+            },
+            TestCase {
+                input: "#.##\n#v.#\n##.#",
+                output: ParseValleyError::VerticalBlizzardInGapColumn {
+                    position: (1, 1),
+                },
+            },
+            TestCase {
+                input: "#.##\n#.^#\n##.#",
+                output: ParseValleyError::VerticalBlizzardInGapColumn {
+                    position: (2, 1),
+                },
+            },
+        ];
+        for tc in test_cases {
+            assert_eq!(tc.input.parse::<Valley>(), Err(tc.output));
+        }
+    }
 }
@@ -1,8 +1,13 @@
+mod animation;
+mod blizzards;
 mod input;
 mod output;
 mod part1;
 mod part2;
 
+use std::{io::Write, thread, time::Duration};
+
+use blizzards::BlizzardMap;
 use input::Input;
 
 fn main() {
@@ -10,9 +15,37 @@ fn main() {
         .parse::<Input>()
         .expect("Input should be valid");
 
-    let output = part1::solve(&input);
+    let args = std::env::args().skip(1).collect::<Vec<_>>();
+    if let Some(mode @ ("animate" | "frames")) =
+        args.first().map(String::as_str)
+    {
+        let map = BlizzardMap::from(&input.valley);
+        let (start, goal) = (map.entrance(), map.exit());
+        let waypoints = if args.iter().any(|arg| arg == "--round-trip") {
+            vec![start, goal, start, goal]
+        } else {
+            vec![start, goal]
+        };
+        let path = part2::journey_path(&map, &waypoints)
+            .expect("Exit should be reachable");
+        let mut stdout = std::io::stdout().lock();
+        for frame in animation::frames(&map, &path) {
+            if mode == "animate" {
+                write!(stdout, "\x1b[2J\x1b[H{}", frame)
+                    .and_then(|_| stdout.flush())
+                    .expect("Frame should be written");
+                thread::sleep(Duration::from_millis(100));
+            } else {
+                writeln!(stdout, "{}\n", frame)
+                    .expect("Frame should be written");
+            }
+        }
+        return;
+    }
+
+    let output = part1::solve(&input).expect("Exit should be reachable");
     println!("Part 1: {}", output);
 
-    // let output = part2::solve(&input);
-    // println!("Part 2: {}", output);
+    let output = part2::solve(&input).expect("Exit should be reachable");
+    println!("Part 2: {}", output);
 }
@@ -1,37 +1,172 @@
-use std::collections::HashSet;
-
 use crate::{
-    input::{Cell, Input},
+    blizzards::{BitSet, BlizzardMap},
+    input::Input,
     output::Output,
 };
 
-pub(crate) fn solve(input: &Input) -> Output {
-    let mut valley = input.valley.clone();
+const MOVES: [(isize, isize); 5] = [(0, 0), (-1, 0), (1, 0), (0, -1), (0, 1)];
+
+pub(crate) fn find_path(
+    map: &BlizzardMap,
+    start: (usize, usize),
+    goal: (usize, usize),
+    departure: u32,
+) -> Option<Vec<(usize, usize)>> {
+    let (width, height) = (map.width(), map.height());
+    let period = map.period();
+    let state = |(x, y): (usize, usize), minute: u32| {
+        (minute as usize % period * height + y) * width + x
+    };
+    let mut visited = BitSet::new(width * height * period);
+    let mut moves = vec![0u8; width * height * period];
+    let mut minute = departure;
+    let mut positions = vec![start];
+    visited.insert(state(start, minute));
+    while !positions.contains(&goal) {
+        if positions.is_empty() {
+            return None;
+        }
+        minute += 1;
+        let mut next_positions = Vec::new();
+        for (x, y) in positions {
+            for (index, &(dx, dy)) in MOVES.iter().enumerate() {
+                let Some(position) =
+                    x.checked_add_signed(dx).zip(y.checked_add_signed(dy))
+                else {
+                    continue;
+                };
+                if map.is_free(position, minute as usize)
+                    && !visited.contains(state(position, minute))
+                {
+                    visited.insert(state(position, minute));
+                    moves[state(position, minute)] = index as u8;
+                    next_positions.push(position);
+                }
+            }
+        }
+        positions = next_positions;
+    }
+    let mut path = vec![goal];
+    for minute in (departure + 1..=minute).rev() {
+        let (x, y) = *path.last().unwrap();
+        let (dx, dy) = MOVES[moves[state((x, y), minute)] as usize];
+        path.push((x.wrapping_add_signed(-dx), y.wrapping_add_signed(-dy)));
+    }
+    path.reverse();
+    Some(path)
+}
+
+pub(crate) fn search(
+    map: &BlizzardMap,
+    start: (usize, usize),
+    goal: (usize, usize),
+    departure: u32,
+) -> Option<u32> {
+    find_path(map, start, goal, departure)
+        .map(|path| departure + path.len() as u32 - 1)
+}
+
+pub(crate) fn solve(input: &Input) -> Option<Output> {
+    let map = BlizzardMap::from(&input.valley);
+    let (start, goal) = (map.entrance(), map.exit());
+    search(&map, start, goal, 0).map(|minutes| Output { minutes })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
 
-    let mut minutes = 0;
-    let mut players = HashSet::<(usize, usize)>::new();
-    players.insert((1, 0));
-    loop {
-        minutes += 1;
-        players =
-            players
-                .iter()
-                .flat_map(|player| {
-                    let mut moves = vec![*player, (player.0 - 1, player.1), (player.0 + 1, player.1), (player.0, player.1 + 1)];
-                    if player.1 > 0 {
-                        moves.push((player.0, player.1 - 1));
-                    }
-                    moves
-                })
-                .filter(
-                    |possible_move| matches!(valley[*possible_move], Cell::Blizzards(ref blizzards) if blizzards.is_empty()),
-                )
-                .collect::<HashSet<_>>();
-        if minutes == 1 {
-            break;
+    #[test]
+    fn test_find_path() {
+        struct TestCase {
+            input: ((usize, usize), (usize, usize), u32),
+            output: Option<Vec<(usize, usize)>>,
+        }
+        let input = "#.#####
+#.....#
+#>....#
+#.....#
+#...v.#
+#.....#
+#####.#"
+            .parse::<Input>()
+            .unwrap();
+        let map = BlizzardMap::from(&input.valley);
+        let test_cases = [
+            TestCase {
+                input: ((1, 0), (1, 0), 3),
+                output: Some(vec![(1, 0)]),
+            },
+            TestCase {
+                input: ((1, 0), (1, 2), 0),
+                output: Some(vec![(1, 0), (1, 1), (1, 2)]),
+            },
+            TestCase {
+                input: ((1, 0), (5, 6), 0),
+                output: Some(vec![
+                    (1, 0),
+                    (1, 1),
+                    (2, 1),
+                    (3, 1),
+                    (4, 1),
+                    (5, 1),
+                    (5, 2),
+                    (5, 3),
+                    (5, 4),
+                    (5, 5),
+                    (5, 6),
+                ]),
+            },
+        ];
+        for tc in test_cases {
+            let (start, goal, departure) = tc.input;
+            let result = find_path(&map, start, goal, departure);
+            assert_eq!(result, tc.output);
         }
     }
-    println!("{:?}", players);
 
-    Output { minutes }
+    #[test]
+    fn test_solve() {
+        struct TestCase {
+            input: Input,
+            output: Option<Output>,
+        }
+        let test_cases = [
+            TestCase {
+                input: "#.#####
+#.....#
+#>....#
+#.....#
+#...v.#
+#.....#
+#####.#"
+                    .parse()
+                    .unwrap(),
+                output: Some(Output { minutes: 10 }),
+            },
+            TestCase {
+                input: "#.######
+#>>.<^<#
+#.<..<<#
+#>v.><>#
+#<^v^^>#
+######.#"
+                    .parse()
+                    .unwrap(),
+                output: Some(Output { minutes: 18 }),
+            },
+            TestCase {
+                input: "#.###
+#>>>#
+#...#
+###.#"
+                    .parse()
+                    .unwrap(),
+                output: None,
+            },
+        ];
+        for tc in test_cases {
+            assert_eq!(solve(&tc.input), tc.output);
+        }
+    }
 }
@@ -1 +1,87 @@
+use crate::{
+    blizzards::BlizzardMap, input::Input, output::Output, part1::find_path,
+};
 
+pub(crate) fn journey_path(
+    map: &BlizzardMap,
+    waypoints: &[(usize, usize)],
+) -> Option<Vec<(usize, usize)>> {
+    let mut path = waypoints.first().copied().into_iter().collect::<Vec<_>>();
+    for leg in waypoints.windows(2) {
+        let departure = path.len() as u32 - 1;
+        let leg_path = find_path(map, leg[0], leg[1], departure)?;
+        path.extend(&leg_path[1..]);
+    }
+    Some(path)
+}
+
+pub(crate) fn journey(
+    map: &BlizzardMap,
+    waypoints: &[(usize, usize)],
+) -> Option<u32> {
+    journey_path(map, waypoints).map(|path| path.len().saturating_sub(1) as u32)
+}
+
+pub(crate) fn solve(input: &Input) -> Option<Output> {
+    let map = BlizzardMap::from(&input.valley);
+    let (start, goal) = (map.entrance(), map.exit());
+    journey(&map, &[start, goal, start, goal]).map(|minutes| Output { minutes })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const VALLEY: &str = "#.######
+#>>.<^<#
+#.<..<<#
+#>v.><>#
+#<^v^^>#
+######.#";
+
+    #[test]
+    fn test_journey() {
+        struct TestCase {
+            input: Vec<(usize, usize)>,
+            output: Option<u32>,
+        }
+        let test_cases = [
+            TestCase {
+                input: vec![(1, 0)],
+                output: Some(0),
+            },
+            TestCase {
+                input: vec![(1, 0), (6, 5)],
+                output: Some(18),
+            },
+            TestCase {
+                input: vec![(1, 0), (6, 5), (1, 0)],
+                output: Some(41),
+            },
+            TestCase {
+                input: vec![(1, 0), (6, 5), (1, 0), (6, 5)],
+                output: Some(54),
+            },
+        ];
+        let input = VALLEY.parse::<Input>().unwrap();
+        let map = BlizzardMap::from(&input.valley);
+        for tc in test_cases {
+            assert_eq!(journey(&map, &tc.input), tc.output);
+        }
+    }
+
+    #[test]
+    fn test_solve() {
+        struct TestCase {
+            input: Input,
+            output: Option<Output>,
+        }
+        let test_cases = [TestCase {
+            input: VALLEY.parse().unwrap(),
+            output: Some(Output { minutes: 54 }),
+        }];
+        for tc in test_cases {
+            assert_eq!(solve(&tc.input), tc.output);
+        }
+    }
+}
@@ -0,0 +1,328 @@
+use std::{
+    cmp::Ordering,
+    error::Error,
+    fmt::Display,
+    iter::Sum,
+    ops::{Add, Neg},
+    str::FromStr,
+};
+
+use crate::snafu::{ParseSnafuError, Snafu};
+
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct BigSnafu {
+    digits: Vec<i8>,
+}
+
+impl BigSnafu {
+    fn signum(&self) -> i8 {
+        self.digits.last().map_or(0, |digit| digit.signum())
+    }
+}
+
+impl From<i64> for BigSnafu {
+    fn from(value: i64) -> Self {
+        Self {
+            digits: Snafu::to_digits(value),
+        }
+    }
+}
+
+impl From<Snafu> for BigSnafu {
+    fn from(value: Snafu) -> Self {
+        i64::from(value).into()
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct TryFromBigSnafuError;
+
+impl Display for TryFromBigSnafuError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "SNAFU number out of range for a 64-bit integer")
+    }
+}
+
+impl Error for TryFromBigSnafuError {}
+
+impl TryFrom<&BigSnafu> for i64 {
+    type Error = TryFromBigSnafuError;
+
+    fn try_from(value: &BigSnafu) -> Result<Self, Self::Error> {
+        let value =
+            value.digits.iter().rev().try_fold(0i128, |acc, &digit| {
+                acc.checked_mul(5)
+                    .and_then(|acc| acc.checked_add(digit as i128))
+                    .ok_or(TryFromBigSnafuError)
+            })?;
+        Self::try_from(value).map_err(|_| TryFromBigSnafuError)
+    }
+}
+
+impl TryFrom<BigSnafu> for i64 {
+    type Error = TryFromBigSnafuError;
+
+    fn try_from(value: BigSnafu) -> Result<Self, Self::Error> {
+        Self::try_from(&value)
+    }
+}
+
+impl TryFrom<&BigSnafu> for Snafu {
+    type Error = TryFromBigSnafuError;
+
+    fn try_from(value: &BigSnafu) -> Result<Self, Self::Error> {
+        i64::try_from(value).map(Snafu::from)
+    }
+}
+
+impl FromStr for BigSnafu {
+    type Err = ParseSnafuError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut digits = Snafu::parse_digits(s)?;
+        while digits.last() == Some(&0) {
+            digits.pop();
+        }
+        Ok(Self { digits })
+    }
+}
+
+impl Display for BigSnafu {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if self.digits.is_empty() {
+            return write!(f, "0");
+        }
+        for &digit in self.digits.iter().rev() {
+            write!(f, "{}", Snafu::digit_char(digit))?;
+        }
+        Ok(())
+    }
+}
+
+impl Add for &BigSnafu {
+    type Output = BigSnafu;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        BigSnafu {
+            digits: Snafu::add_digits(&self.digits, &rhs.digits),
+        }
+    }
+}
+
+impl Add for BigSnafu {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        &self + &rhs
+    }
+}
+
+impl Neg for BigSnafu {
+    type Output = Self;
+
+    fn neg(mut self) -> Self::Output {
+        for digit in self.digits.iter_mut() {
+            *digit = -*digit;
+        }
+        self
+    }
+}
+
+impl Sum for BigSnafu {
+    fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(Self::default(), |acc, value| &acc + &value)
+    }
+}
+
+impl<'a> Sum<&'a BigSnafu> for BigSnafu {
+    fn sum<I: Iterator<Item = &'a BigSnafu>>(iter: I) -> Self {
+        iter.fold(Self::default(), |acc, value| &acc + value)
+    }
+}
+
+impl Ord for BigSnafu {
+    fn cmp(&self, other: &Self) -> Ordering {
+        match self.digits.len().cmp(&other.digits.len()) {
+            Ordering::Greater => self.signum().cmp(&0),
+            Ordering::Less => 0.cmp(&other.signum()),
+            Ordering::Equal => {
+                self.digits.iter().rev().cmp(other.digits.iter().rev())
+            }
+        }
+    }
+}
+
+impl PartialOrd for BigSnafu {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn big_snafu_from_str_display() {
+        struct TestCase {
+            input: String,
+            output: String,
+        }
+        let test_cases = [
+            TestCase {
+                input: "0".to_string(),
+                output: "0".to_string(),
+            },
+            TestCase {
+                input: "000".to_string(),
+                output: "0".to_string(),
+            },
+            TestCase {
+                input: "001=".to_string(),
+                output: "1=".to_string(),
+            },
+            TestCase {
+                input: "-".to_string(),
+                output: "-".to_string(),
+            },
+            TestCase {
+                input: "1121-1110-1=0".to_string(),
+                output: "1121-1110-1=0".to_string(),
+            },
+            TestCase {
+                input: "12=-0".repeat(1000),
+                output: "12=-0".repeat(1000),
+            },
+        ];
+        for tc in test_cases {
+            let result = tc.input.parse::<BigSnafu>().unwrap().to_string();
+            assert_eq!(result, tc.output);
+        }
+    }
+
+    #[test]
+    fn big_snafu_from_str_error() {
+        assert_eq!("".parse::<BigSnafu>(), Err(ParseSnafuError::Empty));
+        assert_eq!(
+            "12a".parse::<BigSnafu>(),
+            Err(ParseSnafuError::InvalidCharacter {
+                character: 'a',
+                column: 3,
+            })
+        );
+    }
+
+    #[test]
+    fn big_snafu_add() {
+        struct TestCase {
+            input: (String, String),
+            output: String,
+        }
+        let test_cases = [
+            TestCase {
+                input: ("1".to_string(), "1".to_string()),
+                output: "2".to_string(),
+            },
+            TestCase {
+                input: ("2".to_string(), "1".to_string()),
+                output: "1=".to_string(),
+            },
+            TestCase {
+                input: ("1=".to_string(), "-".to_string()),
+                output: "2".to_string(),
+            },
+            TestCase {
+                input: ("2222".to_string(), "1".to_string()),
+                output: "1====".to_string(),
+            },
+            TestCase {
+                input: ("1=-0-2".to_string(), "12111".to_string()),
+                output: "1-111=".to_string(),
+            },
+            TestCase {
+                input: ("1".to_string(), "-".to_string()),
+                output: "0".to_string(),
+            },
+            TestCase {
+                input: ("2".repeat(3000), "1".to_string()),
+                output: format!("1{}", "=".repeat(3000)),
+            },
+            TestCase {
+                input: ("=".repeat(3000), "-".to_string()),
+                output: format!("-{}2", "2".repeat(2999)),
+            },
+            TestCase {
+                input: ("1".repeat(2000), "-".repeat(2000)),
+                output: "0".to_string(),
+            },
+        ];
+        for tc in test_cases {
+            let lhs = tc.input.0.parse::<BigSnafu>().unwrap();
+            let rhs = tc.input.1.parse::<BigSnafu>().unwrap();
+            assert_eq!((lhs + rhs).to_string(), tc.output);
+        }
+    }
+
+    #[test]
+    fn big_snafu_matches_native() {
+        let values = (-300..300)
+            .chain([i64::MIN, i64::MIN + 1, i64::MAX - 1, i64::MAX])
+            .collect::<Vec<_>>();
+        for &lhs in &values {
+            assert_eq!(i64::try_from(BigSnafu::from(lhs)), Ok(lhs));
+            for &rhs in &values {
+                let (a, b) = (BigSnafu::from(lhs), BigSnafu::from(rhs));
+                assert_eq!(a.cmp(&b), lhs.cmp(&rhs));
+                assert_eq!(i64::try_from(a + b).ok(), lhs.checked_add(rhs));
+            }
+        }
+    }
+
+    #[test]
+    fn big_snafu_sum() {
+        let values = ["1=-0-2", "12111", "2=0=", "21", "2=01", "111", "20012"];
+        let result = values
+            .iter()
+            .map(|value| value.parse::<BigSnafu>().unwrap())
+            .sum::<BigSnafu>();
+        assert_eq!(result, BigSnafu::from(4351));
+    }
+
+    #[test]
+    fn big_snafu_try_into_i64() {
+        struct TestCase {
+            input: String,
+            output: Result<i64, TryFromBigSnafuError>,
+        }
+        let test_cases = [
+            TestCase {
+                input: "0".to_string(),
+                output: Ok(0),
+            },
+            TestCase {
+                input: "2=-01".to_string(),
+                output: Ok(976),
+            },
+            TestCase {
+                input: "1".repeat(27),
+                output: Ok(1862645149230957031),
+            },
+            TestCase {
+                input: format!("1{}", "0".repeat(27)),
+                output: Ok(7450580596923828125),
+            },
+            TestCase {
+                input: format!("1{}", "0".repeat(28)),
+                output: Err(TryFromBigSnafuError),
+            },
+            TestCase {
+                input: "2".repeat(28),
+                output: Err(TryFromBigSnafuError),
+            },
+        ];
+        for tc in test_cases {
+            let result = i64::try_from(tc.input.parse::<BigSnafu>().unwrap());
+            assert_eq!(result, tc.output);
+        }
+    }
+}
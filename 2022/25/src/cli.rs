@@ -0,0 +1,254 @@
+use std::{
+    error::Error,
+    fmt::Display,
+    io::{self, Write},
+    str::FromStr,
+};
+
+use day25::{
+    big_snafu::BigSnafu,
+    numeral::{Decimal, ParseNumeralError},
+    snafu::Snafu,
+};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) enum Command {
+    ToSnafu,
+    FromSnafu,
+    Sum,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub(crate) struct ParseCommandError(String);
+
+impl Display for ParseCommandError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "unknown command: {}", self.0)
+    }
+}
+
+impl Error for ParseCommandError {}
+
+impl FromStr for Command {
+    type Err = ParseCommandError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "to-snafu" => Ok(Self::ToSnafu),
+            "from-snafu" => Ok(Self::FromSnafu),
+            "sum" => Ok(Self::Sum),
+            _ => Err(ParseCommandError(s.to_string())),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub(crate) enum RunError {
+    Parse {
+        number: String,
+        source: ParseNumeralError,
+    },
+    Io(io::Error),
+}
+
+impl Display for RunError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Parse { number, source } => {
+                write!(f, "failed to parse {:?}: {}", number, source)
+            }
+            Self::Io(err) => write!(f, "input/output error: {}", err),
+        }
+    }
+}
+
+impl Error for RunError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            Self::Parse { source, .. } => Some(source),
+            Self::Io(err) => Some(err),
+        }
+    }
+}
+
+impl From<io::Error> for RunError {
+    fn from(value: io::Error) -> Self {
+        Self::Io(value)
+    }
+}
+
+fn parse<T: FromStr<Err = ParseNumeralError>>(
+    number: &str,
+) -> Result<T, RunError> {
+    number.parse().map_err(|source| RunError::Parse {
+        number: number.to_string(),
+        source,
+    })
+}
+
+pub(crate) fn run<I, S, W>(
+    command: Command,
+    numbers: I,
+    output: &mut W,
+) -> Result<(), RunError>
+where
+    I: IntoIterator<Item = io::Result<S>>,
+    S: AsRef<str>,
+    W: Write,
+{
+    let mut total = BigSnafu::default();
+    for number in numbers {
+        let number = number?;
+        let number = number.as_ref().trim();
+        if number.is_empty() {
+            continue;
+        }
+        match command {
+            Command::ToSnafu => {
+                let decimal = parse::<Decimal>(number)?;
+                writeln!(output, "{}", Snafu::from(i64::from(decimal)))?;
+            }
+            Command::FromSnafu => {
+                let snafu = parse::<Snafu>(number)?;
+                writeln!(output, "{}", Decimal::from(i64::from(snafu)))?;
+            }
+            Command::Sum => total = total + parse(number)?,
+        }
+        output.flush()?;
+    }
+    if command == Command::Sum {
+        writeln!(output, "{}", total)?;
+        output.flush()?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn command_from_str() {
+        struct TestCase {
+            input: &'static str,
+            output: Result<Command, ParseCommandError>,
+        }
+        let test_cases = [
+            TestCase {
+                input: "to-snafu",
+                output: Ok(Command::ToSnafu),
+            },
+            TestCase {
+                input: "from-snafu",
+                output: Ok(Command::FromSnafu),
+            },
+            TestCase {
+                input: "sum",
+                output: Ok(Command::Sum),
+            },
+            TestCase {
+                input: "product",
+                output: Err(ParseCommandError("product".to_string())),
+            },
+        ];
+        for tc in test_cases {
+            assert_eq!(tc.input.parse(), tc.output);
+        }
+    }
+
+    #[test]
+    fn run_command() {
+        struct TestCase {
+            input: (Command, Vec<&'static str>),
+            output: &'static str,
+        }
+        let test_cases = [
+            TestCase {
+                input: (Command::ToSnafu, vec!["0", "2022", "-3", " 12345\n"]),
+                output: "0\n1=11-2\n-2\n1-0---0\n",
+            },
+            TestCase {
+                input: (Command::FromSnafu, vec!["1=11-2", "", "2=-01", "="]),
+                output: "2022\n976\n-2\n",
+            },
+            TestCase {
+                input: (
+                    Command::Sum,
+                    vec![
+                        "1=-0-2", "12111", "2=0=", "21", "2=01", "111",
+                        "20012", "112", "1=-1=", "1-12", "12", "1=", "122",
+                    ],
+                ),
+                output: "2=-1=0\n",
+            },
+            TestCase {
+                input: (Command::Sum, vec![]),
+                output: "0\n",
+            },
+            TestCase {
+                input: (
+                    Command::Sum,
+                    vec!["1110--=-02=100==0-0=11=11212", "1"],
+                ),
+                output: "1110--=-02=100==0-0=11=1122=\n",
+            },
+            TestCase {
+                input: (
+                    Command::Sum,
+                    vec!["2222222222222222222222222222222222222222", "1"],
+                ),
+                output: "1========================================\n",
+            },
+        ];
+        for tc in test_cases {
+            let mut output = Vec::new();
+            run(tc.input.0, tc.input.1.into_iter().map(Ok), &mut output)
+                .unwrap();
+            assert_eq!(String::from_utf8(output).unwrap(), tc.output);
+        }
+    }
+
+    #[test]
+    fn run_command_error() {
+        struct TestCase {
+            input: (Command, Vec<&'static str>),
+            output: &'static str,
+        }
+        let test_cases = [
+            TestCase {
+                input: (Command::ToSnafu, vec!["12", "1x"]),
+                output: "failed to parse \"1x\": invalid character 'x' at column 2",
+            },
+            TestCase {
+                input: (Command::FromSnafu, vec!["13"]),
+                output: "failed to parse \"13\": invalid character '3' at column 2",
+            },
+            TestCase {
+                input: (Command::Sum, vec!["1", "2=-x"]),
+                output: "failed to parse \"2=-x\": invalid character 'x' at column 4",
+            },
+        ];
+        for tc in test_cases {
+            let err = run(
+                tc.input.0,
+                tc.input.1.into_iter().map(Ok),
+                &mut Vec::new(),
+            )
+            .unwrap_err();
+            assert_eq!(err.to_string(), tc.output);
+        }
+    }
+
+    #[test]
+    fn run_command_streams_until_read_error() {
+        let numbers = [
+            Ok("2022"),
+            Err(io::Error::other("stdin closed")),
+            Ok("12345"),
+        ];
+        let mut output = Vec::new();
+        let err = run(Command::ToSnafu, numbers, &mut output).unwrap_err();
+        assert_eq!(err.to_string(), "input/output error: stdin closed");
+        assert_eq!(String::from_utf8(output).unwrap(), "1=11-2\n");
+    }
+}
@@ -1,20 +1,30 @@
 use std::{error::Error, fmt::Display, str::FromStr};
 
-use crate::snafu::{ParseSnafuError, Snafu};
+use day25::snafu::{ParseSnafuError, Snafu};
 
+#[derive(Debug, PartialEq, Eq)]
 pub(crate) struct Input {
     pub(crate) fuel_requirements: Vec<Snafu>,
 }
 
-#[derive(Clone, Copy, Debug)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub(crate) enum ParseInputError {
-    ParseSnafuError(ParseSnafuError),
+    ParseSnafuError {
+        line: usize,
+        source: ParseSnafuError,
+    },
 }
 
 impl Display for ParseInputError {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
-            Self::ParseSnafuError(err) => write!(f, "{}", err),
+            Self::ParseSnafuError { line, source } => {
+                write!(
+                    f,
+                    "failed to parse SNAFU number on line {}: {}",
+                    line, source
+                )
+            }
         }
     }
 }
@@ -22,17 +32,11 @@ impl Display for ParseInputError {
 impl Error for ParseInputError {
     fn source(&self) -> Option<&(dyn Error + 'static)> {
         match self {
-            Self::ParseSnafuError(err) => Some(err),
+            Self::ParseSnafuError { source, .. } => Some(source),
         }
     }
 }
 
-impl From<ParseSnafuError> for ParseInputError {
-    fn from(value: ParseSnafuError) -> Self {
-        Self::ParseSnafuError(value)
-    }
-}
-
 impl FromStr for Input {
     type Err = ParseInputError;
 
@@ -40,8 +44,70 @@ impl FromStr for Input {
         Ok(Self {
             fuel_requirements: s
                 .lines()
-                .map(|line| line.parse())
+                .enumerate()
+                .map(|(index, line)| {
+                    line.parse().map_err(|source| {
+                        ParseInputError::ParseSnafuError {
+                            line: index + 1,
+                            source,
+                        }
+                    })
+                })
                 .collect::<Result<Vec<_>, _>>()?,
         })
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn input_from_str() {
+        struct TestCase {
+            input: &'static str,
+            output: Result<Input, ParseInputError>,
+        }
+        let test_cases = [
+            TestCase {
+                input: "1=-0-2\n12111\n2=0=\n",
+                output: Ok(Input {
+                    fuel_requirements: vec![
+                        Snafu::from(1747),
+                        Snafu::from(906),
+                        Snafu::from(198),
+                    ],
+                }),
+            },
+            TestCase {
+                input: "1=-0-2\n12111\n2=3=",
+                output: Err(ParseInputError::ParseSnafuError {
+                    line: 3,
+                    source: ParseSnafuError::InvalidCharacter {
+                        character: '3',
+                        column: 3,
+                    },
+                }),
+            },
+            TestCase {
+                input: "1=-0-2\n\n2=0=",
+                output: Err(ParseInputError::ParseSnafuError {
+                    line: 2,
+                    source: ParseSnafuError::Empty,
+                }),
+            },
+        ];
+        for tc in test_cases {
+            assert_eq!(tc.input.parse::<Input>(), tc.output);
+        }
+    }
+
+    #[test]
+    fn parse_input_error_display() {
+        let err = "1=-0-2\n12111\n2=3=".parse::<Input>().unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "failed to parse SNAFU number on line 3: invalid character '3' at column 3"
+        );
+    }
+}
@@ -0,0 +1,3 @@
+pub mod big_snafu;
+pub mod numeral;
+pub mod snafu;
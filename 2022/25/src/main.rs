@@ -1,15 +1,38 @@
+mod cli;
 mod input;
 mod output;
 mod part1;
-mod snafu;
 
+use std::io::{self, BufRead};
+
+use cli::Command;
 use input::Input;
 
 fn main() {
+    let args = std::env::args().skip(1).collect::<Vec<_>>();
+    if let Some(command) = args.first() {
+        let command = command.parse::<Command>().unwrap_or_else(|err| {
+            eprintln!("{}", err);
+            std::process::exit(2);
+        });
+        let mut stdout = io::stdout().lock();
+        let result = if args.len() > 1 {
+            cli::run(command, args[1..].iter().map(Ok), &mut stdout)
+        } else {
+            cli::run(command, io::stdin().lock().lines(), &mut stdout)
+        };
+        if let Err(err) = result {
+            eprintln!("{}", err);
+            std::process::exit(1);
+        }
+        return;
+    }
+
     let input = include_str!("input.txt")
         .parse::<Input>()
         .expect("Input should be valid");
 
-    let output = part1::solve(&input);
+    let output =
+        part1::solve(&input).expect("Total fuel should fit in 64 bits");
     println!("Part 1: {}", output);
 }
@@ -0,0 +1,562 @@
+use std::{
+    error::Error,
+    fmt::Display,
+    iter::{Product, Sum},
+    marker::PhantomData,
+    ops::{Add, Mul, Neg, Sub},
+    str::FromStr,
+};
+
+pub trait Alphabet {
+    const DIGITS: &'static [char];
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ParseNumeralError {
+    Empty,
+    InvalidCharacter { character: char, column: usize },
+    Overflow,
+}
+
+impl Display for ParseNumeralError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match *self {
+            Self::Empty => write!(f, "cannot parse numeral from empty string"),
+            Self::InvalidCharacter { character, column } => write!(
+                f,
+                "invalid character {:?} at column {}",
+                character, column
+            ),
+            Self::Overflow => {
+                write!(f, "numeral is too large for a 64-bit integer")
+            }
+        }
+    }
+}
+
+impl Error for ParseNumeralError {}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct BalancedBase<const B: usize, A>(i64, PhantomData<A>);
+
+impl<const B: usize, A: Alphabet> BalancedBase<B, A> {
+    const HALF: i8 = {
+        assert!(B % 2 == 1, "balanced base must be odd");
+        assert!(B <= i8::MAX as usize, "balanced base is too large");
+        assert!(
+            A::DIGITS.len() == B,
+            "alphabet must have one digit per value"
+        );
+        (B / 2) as i8
+    };
+
+    pub fn digit_value(char: char) -> Option<i8> {
+        A::DIGITS
+            .iter()
+            .position(|&digit| digit == char)
+            .map(|index| index as i8 - Self::HALF)
+    }
+
+    pub fn digit_char(digit: i8) -> char {
+        A::DIGITS[(digit + Self::HALF) as usize]
+    }
+
+    pub fn to_digits(mut value: i64) -> Vec<i8> {
+        let mut digits = Vec::new();
+        while value != 0 {
+            let remainder = value.rem_euclid(B as i64) as i8;
+            value = value.div_euclid(B as i64);
+            if remainder > Self::HALF {
+                digits.push(remainder - B as i8);
+                value += 1;
+            } else {
+                digits.push(remainder);
+            }
+        }
+        digits
+    }
+
+    pub fn add_digits(lhs: &[i8], rhs: &[i8]) -> Vec<i8> {
+        let length = lhs.len().max(rhs.len());
+        let mut digits = Vec::with_capacity(length + 1);
+        let mut carry = 0;
+        for i in 0..length {
+            let mut digit = lhs.get(i).copied().unwrap_or(0)
+                + rhs.get(i).copied().unwrap_or(0)
+                + carry;
+            carry = 0;
+            if digit > Self::HALF {
+                digit -= B as i8;
+                carry = 1;
+            } else if digit < -Self::HALF {
+                digit += B as i8;
+                carry = -1;
+            }
+            digits.push(digit);
+        }
+        if carry != 0 {
+            digits.push(carry);
+        }
+        while digits.last() == Some(&0) {
+            digits.pop();
+        }
+        digits
+    }
+
+    pub fn parse_digits(s: &str) -> Result<Vec<i8>, ParseNumeralError> {
+        if s.is_empty() {
+            return Err(ParseNumeralError::Empty);
+        }
+        let mut digits = s
+            .chars()
+            .enumerate()
+            .map(|(index, character)| {
+                Self::digit_value(character).ok_or(
+                    ParseNumeralError::InvalidCharacter {
+                        character,
+                        column: index + 1,
+                    },
+                )
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        digits.reverse();
+        Ok(digits)
+    }
+}
+
+impl<const B: usize, A: Alphabet> FromStr for BalancedBase<B, A> {
+    type Err = ParseNumeralError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let val = Self::parse_digits(s)?.into_iter().rev().try_fold(
+            0i128,
+            |val, digit| {
+                val.checked_mul(B as i128)
+                    .and_then(|val| val.checked_add(digit as i128))
+                    .ok_or(Self::Err::Overflow)
+            },
+        )?;
+        i64::try_from(val)
+            .map(Self::from)
+            .map_err(|_| Self::Err::Overflow)
+    }
+}
+
+impl<const B: usize, A: Alphabet> Display for BalancedBase<B, A> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if self.0 == 0 {
+            return write!(f, "{}", Self::digit_char(0));
+        }
+        for digit in Self::to_digits(self.0).into_iter().rev() {
+            write!(f, "{}", Self::digit_char(digit))?;
+        }
+        Ok(())
+    }
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Positional<const B: usize, A>(i64, PhantomData<A>);
+
+impl<const B: usize, A: Alphabet> Positional<B, A> {
+    const BASE: i64 = {
+        assert!(B >= 2, "positional base must be at least 2");
+        assert!(
+            A::DIGITS.len() == B,
+            "alphabet must have one digit per value"
+        );
+        B as i64
+    };
+}
+
+impl<const B: usize, A: Alphabet> FromStr for Positional<B, A> {
+    type Err = ParseNumeralError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (negative, digits) = match s.strip_prefix('-') {
+            Some(digits) => (true, digits),
+            None => (false, s),
+        };
+        if digits.is_empty() {
+            return Err(Self::Err::Empty);
+        }
+        let mut val = 0i64;
+        for (index, character) in s.chars().enumerate().skip(negative as usize)
+        {
+            let digit = A::DIGITS
+                .iter()
+                .position(|&digit| digit == character)
+                .ok_or(Self::Err::InvalidCharacter {
+                    character,
+                    column: index + 1,
+                })?;
+            val = val
+                .checked_mul(Self::BASE)
+                .and_then(|val| val.checked_sub(digit as i64))
+                .ok_or(Self::Err::Overflow)?;
+        }
+        if !negative {
+            val = val.checked_neg().ok_or(Self::Err::Overflow)?;
+        }
+        Ok(Self::from(val))
+    }
+}
+
+impl<const B: usize, A: Alphabet> Display for Positional<B, A> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if self.0 < 0 {
+            write!(f, "-")?;
+        }
+        let mut value = self.0.unsigned_abs();
+        let mut digits = Vec::new();
+        loop {
+            digits.push(A::DIGITS[(value % Self::BASE as u64) as usize]);
+            value /= Self::BASE as u64;
+            if value == 0 {
+                break;
+            }
+        }
+        write!(f, "{}", digits.into_iter().rev().collect::<String>())
+    }
+}
+
+macro_rules! impl_numeral {
+    ($numeral:ident) => {
+        impl<const B: usize, A> $numeral<B, A> {
+            pub fn checked_add(self, rhs: Self) -> Option<Self> {
+                self.0.checked_add(rhs.0).map(Self::from)
+            }
+
+            pub fn checked_sub(self, rhs: Self) -> Option<Self> {
+                self.0.checked_sub(rhs.0).map(Self::from)
+            }
+
+            pub fn checked_mul(self, rhs: Self) -> Option<Self> {
+                self.0.checked_mul(rhs.0).map(Self::from)
+            }
+
+            pub fn checked_neg(self) -> Option<Self> {
+                self.0.checked_neg().map(Self::from)
+            }
+        }
+
+        impl<const B: usize, A> From<i64> for $numeral<B, A> {
+            fn from(value: i64) -> Self {
+                Self(value, PhantomData)
+            }
+        }
+
+        impl<const B: usize, A> From<$numeral<B, A>> for i64 {
+            fn from(value: $numeral<B, A>) -> Self {
+                value.0
+            }
+        }
+
+        impl<const B: usize, A> Add for $numeral<B, A> {
+            type Output = Self;
+
+            fn add(self, rhs: Self) -> Self::Output {
+                Self::from(self.0 + rhs.0)
+            }
+        }
+
+        impl<const B: usize, A> Sub for $numeral<B, A> {
+            type Output = Self;
+
+            fn sub(self, rhs: Self) -> Self::Output {
+                Self::from(self.0 - rhs.0)
+            }
+        }
+
+        impl<const B: usize, A> Mul for $numeral<B, A> {
+            type Output = Self;
+
+            fn mul(self, rhs: Self) -> Self::Output {
+                Self::from(self.0 * rhs.0)
+            }
+        }
+
+        impl<const B: usize, A> Neg for $numeral<B, A> {
+            type Output = Self;
+
+            fn neg(self) -> Self::Output {
+                Self::from(-self.0)
+            }
+        }
+
+        impl<const B: usize, A> Sum for $numeral<B, A> {
+            fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+                iter.fold(Self::from(0), Add::add)
+            }
+        }
+
+        impl<'a, const B: usize, A: Copy + 'a> Sum<&'a $numeral<B, A>>
+            for $numeral<B, A>
+        {
+            fn sum<I: Iterator<Item = &'a Self>>(iter: I) -> Self {
+                iter.copied().sum()
+            }
+        }
+
+        impl<const B: usize, A> Product for $numeral<B, A> {
+            fn product<I: Iterator<Item = Self>>(iter: I) -> Self {
+                iter.fold(Self::from(1), Mul::mul)
+            }
+        }
+
+        impl<'a, const B: usize, A: Copy + 'a> Product<&'a $numeral<B, A>>
+            for $numeral<B, A>
+        {
+            fn product<I: Iterator<Item = &'a Self>>(iter: I) -> Self {
+                iter.copied().product()
+            }
+        }
+    };
+}
+
+impl_numeral!(BalancedBase);
+impl_numeral!(Positional);
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct DecimalDigits;
+
+impl Alphabet for DecimalDigits {
+    const DIGITS: &'static [char] =
+        &['0', '1', '2', '3', '4', '5', '6', '7', '8', '9'];
+}
+
+pub type Decimal = Positional<10, DecimalDigits>;
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct TernaryDigits;
+
+impl Alphabet for TernaryDigits {
+    const DIGITS: &'static [char] = &['T', '0', '1'];
+}
+
+pub type BalancedTernary = BalancedBase<3, TernaryDigits>;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(
+        Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash,
+    )]
+    struct BinaryDigits;
+
+    impl Alphabet for BinaryDigits {
+        const DIGITS: &'static [char] = &['0', '1'];
+    }
+
+    type Binary = Positional<2, BinaryDigits>;
+
+    #[derive(
+        Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash,
+    )]
+    struct HexadecimalDigits;
+
+    impl Alphabet for HexadecimalDigits {
+        const DIGITS: &'static [char] = &[
+            '0', '1', '2', '3', '4', '5', '6', '7', '8', '9', 'a', 'b', 'c',
+            'd', 'e', 'f',
+        ];
+    }
+
+    type Hexadecimal = Positional<16, HexadecimalDigits>;
+
+    #[test]
+    fn balanced_ternary_from_str() {
+        struct TestCase {
+            input: &'static str,
+            output: Result<BalancedTernary, ParseNumeralError>,
+        }
+        let test_cases = [
+            TestCase {
+                input: "0",
+                output: Ok(BalancedTernary::from(0)),
+            },
+            TestCase {
+                input: "1",
+                output: Ok(BalancedTernary::from(1)),
+            },
+            TestCase {
+                input: "T",
+                output: Ok(BalancedTernary::from(-1)),
+            },
+            TestCase {
+                input: "1T",
+                output: Ok(BalancedTernary::from(2)),
+            },
+            TestCase {
+                input: "10",
+                output: Ok(BalancedTernary::from(3)),
+            },
+            TestCase {
+                input: "11T",
+                output: Ok(BalancedTernary::from(11)),
+            },
+            TestCase {
+                input: "T01",
+                output: Ok(BalancedTernary::from(-8)),
+            },
+            TestCase {
+                input: "1T0T1",
+                output: Ok(BalancedTernary::from(52)),
+            },
+            TestCase {
+                input: "1=",
+                output: Err(ParseNumeralError::InvalidCharacter {
+                    character: '=',
+                    column: 2,
+                }),
+            },
+            TestCase {
+                input: "",
+                output: Err(ParseNumeralError::Empty),
+            },
+            TestCase {
+                input: "10000000000000000000000000000000000000000",
+                output: Err(ParseNumeralError::Overflow),
+            },
+        ];
+        for tc in test_cases {
+            assert_eq!(tc.input.parse(), tc.output);
+        }
+    }
+
+    #[test]
+    fn balanced_ternary_round_trip() {
+        for value in -10_000..=10_000 {
+            let formatted = BalancedTernary::from(value).to_string();
+            assert!(formatted == "0" || !formatted.starts_with('0'));
+            assert_eq!(formatted.parse(), Ok(BalancedTernary::from(value)));
+        }
+        for value in [i64::MIN, i64::MAX] {
+            let formatted = BalancedTernary::from(value).to_string();
+            assert_eq!(formatted.parse(), Ok(BalancedTernary::from(value)));
+        }
+    }
+
+    #[test]
+    fn balanced_ternary_add_digits() {
+        for lhs in -100..100 {
+            for rhs in -100..100 {
+                let result = BalancedTernary::add_digits(
+                    &BalancedTernary::to_digits(lhs),
+                    &BalancedTernary::to_digits(rhs),
+                );
+                assert_eq!(result, BalancedTernary::to_digits(lhs + rhs));
+            }
+        }
+    }
+
+    #[test]
+    fn balanced_ternary_arithmetic() {
+        let values = ["1T0T1", "T01", "11T", "1"]
+            .map(|value| value.parse::<BalancedTernary>().unwrap());
+        assert_eq!(values.iter().sum::<BalancedTernary>().to_string(), "1T01T");
+        assert_eq!(
+            values.iter().product::<BalancedTernary>().to_string(),
+            "T10T1TTTT"
+        );
+        assert_eq!((values[0] - values[1]).to_string(), "1T1T0");
+        assert_eq!((-values[0]).to_string(), "T101T");
+    }
+
+    #[test]
+    fn positional_from_str_display() {
+        struct TestCase {
+            input: &'static str,
+            output: i64,
+        }
+        let test_cases = [
+            TestCase {
+                input: "0",
+                output: 0,
+            },
+            TestCase {
+                input: "101010",
+                output: 42,
+            },
+            TestCase {
+                input: "-1",
+                output: -1,
+            },
+            TestCase {
+                input: "-1000000000000000000000000000000000000000000000000000000000000000",
+                output: i64::MIN,
+            },
+            TestCase {
+                input: "111111111111111111111111111111111111111111111111111111111111111",
+                output: i64::MAX,
+            },
+        ];
+        for tc in test_cases {
+            let result = tc.input.parse::<Binary>().unwrap();
+            assert_eq!(i64::from(result), tc.output);
+            assert_eq!(result.to_string(), tc.input);
+        }
+        assert_eq!(Hexadecimal::from(-48879).to_string(), "-beef");
+        assert_eq!(Decimal::from(i64::MIN).to_string(), i64::MIN.to_string());
+    }
+
+    #[test]
+    fn positional_from_str_error() {
+        struct TestCase {
+            input: &'static str,
+            output: ParseNumeralError,
+        }
+        let test_cases = [
+            TestCase {
+                input: "",
+                output: ParseNumeralError::Empty,
+            },
+            TestCase {
+                input: "-",
+                output: ParseNumeralError::Empty,
+            },
+            TestCase {
+                input: "12a",
+                output: ParseNumeralError::InvalidCharacter {
+                    character: 'a',
+                    column: 3,
+                },
+            },
+            TestCase {
+                input: "--1",
+                output: ParseNumeralError::InvalidCharacter {
+                    character: '-',
+                    column: 2,
+                },
+            },
+            TestCase {
+                input: "9223372036854775808",
+                output: ParseNumeralError::Overflow,
+            },
+            TestCase {
+                input: "-9223372036854775809",
+                output: ParseNumeralError::Overflow,
+            },
+        ];
+        for tc in test_cases {
+            assert_eq!(tc.input.parse::<Decimal>(), Err(tc.output));
+        }
+        assert_eq!(
+            "-9223372036854775808".parse::<Decimal>(),
+            Ok(Decimal::from(i64::MIN))
+        );
+    }
+
+    #[test]
+    fn positional_arithmetic() {
+        let (lhs, rhs) = (Decimal::from(2022), Decimal::from(-7));
+        assert_eq!((lhs + rhs).to_string(), "2015");
+        assert_eq!((lhs - rhs).to_string(), "2029");
+        assert_eq!((lhs * rhs).to_string(), "-14154");
+        assert_eq!((-lhs).to_string(), "-2022");
+        assert_eq!(Decimal::from(i64::MIN).checked_sub(Decimal::from(1)), None);
+        assert_eq!(Decimal::from(i64::MAX).checked_mul(Decimal::from(2)), None);
+        assert_eq!(Decimal::from(i64::MIN).checked_neg(), None);
+        assert_eq!(lhs.checked_neg(), Some(Decimal::from(-2022)));
+    }
+}
@@ -1,6 +1,6 @@
 use std::fmt::Display;
 
-use crate::snafu::Snafu;
+use day25::snafu::Snafu;
 
 #[derive(Debug, Clone, Copy)]
 pub(crate) struct Output {
@@ -1,13 +1,42 @@
-use crate::{input::Input, output::Output, snafu::Snafu};
-
-pub(crate) fn solve(input: &Input) -> Output {
-    Output {
-        total_fuel: Snafu(
-            input
-                .fuel_requirements
-                .iter()
-                .map(|Snafu(fuel_requirement)| fuel_requirement)
-                .sum(),
-        ),
+use day25::snafu::Snafu;
+
+use crate::{input::Input, output::Output};
+
+pub(crate) fn solve(input: &Input) -> Option<Output> {
+    input
+        .fuel_requirements
+        .iter()
+        .try_fold(Snafu::default(), |total, &fuel| total.checked_add(fuel))
+        .map(|total_fuel| Output { total_fuel })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_solve() {
+        struct TestCase {
+            input: Input,
+            output: Option<Snafu>,
+        }
+        let test_cases = [
+            TestCase {
+                input: include_str!("input.txt").parse().unwrap(),
+                output: Some("2=0--0---11--01=-100".parse().unwrap()),
+            },
+            TestCase {
+                input: "".parse().unwrap(),
+                output: Some(Snafu::from(0)),
+            },
+            TestCase {
+                input: "1110--=-02=100==0-0=11=11212\n1".parse().unwrap(),
+                output: None,
+            },
+        ];
+        for tc in test_cases {
+            let result = solve(&tc.input).map(|output| output.total_fuel);
+            assert_eq!(result, tc.output);
+        }
     }
 }
@@ -1,112 +1,15 @@
-use std::{error::Error, fmt::Display, str::FromStr};
+use crate::numeral::{Alphabet, BalancedBase, ParseNumeralError};
 
-#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
-pub(crate) struct Snafu(pub(crate) i64);
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct SnafuDigits;
 
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
-pub(crate) enum ParseSnafuError {
-    InvalidCharacter,
+impl Alphabet for SnafuDigits {
+    const DIGITS: &'static [char] = &['=', '-', '0', '1', '2'];
 }
 
-impl Display for ParseSnafuError {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match *self {
-            Self::InvalidCharacter => write!(
-                f,
-                "Encountered invalid character when parsing SNAFU number."
-            ),
-        }
-    }
-}
-
-impl Error for ParseSnafuError {}
+pub type Snafu = BalancedBase<5, SnafuDigits>;
 
-impl FromStr for Snafu {
-    type Err = ParseSnafuError;
-
-    fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let mut val = 0;
-        let mut multiplier = 1;
-        for char in s.chars().rev() {
-            val += match char {
-                '=' => -multiplier * 2,
-                '-' => -multiplier,
-                '0' => 0,
-                '1' => multiplier,
-                '2' => multiplier * 2,
-                _ => return Err(Self::Err::InvalidCharacter),
-            };
-            multiplier *= 5;
-        }
-        Ok(Self(val))
-    }
-}
-
-impl Display for Snafu {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        let mut power_of_5 = 1;
-        let mut power_of_5_sum = power_of_5;
-        let mut length = 1;
-        while power_of_5 * 2 < self.0 {
-            power_of_5 *= 5;
-            power_of_5_sum += power_of_5;
-            length += 1;
-        }
-        power_of_5_sum -= power_of_5;
-        let mut accumulator = 0;
-
-        write!(
-            f,
-            "{}",
-            (0..length)
-                .map(|_| {
-                    let diff = self.0 - accumulator;
-                    let digit = if diff <= -power_of_5 * 2 {
-                        '='
-                    } else if diff < -power_of_5 {
-                        if -power_of_5 * 2 + power_of_5_sum * 2 >= diff {
-                            '='
-                        } else {
-                            '-'
-                        }
-                    } else if diff < 0 {
-                        if -power_of_5 + power_of_5_sum * 2 >= diff {
-                            '-'
-                        } else {
-                            '0'
-                        }
-                    } else if diff < power_of_5 {
-                        if power_of_5_sum * 2 >= diff {
-                            '0'
-                        } else {
-                            '1'
-                        }
-                    } else if diff < power_of_5 * 2 {
-                        if power_of_5 + power_of_5_sum * 2 >= diff {
-                            '1'
-                        } else {
-                            '2'
-                        }
-                    } else {
-                        '2'
-                    };
-                    accumulator += match digit {
-                        '=' => -power_of_5 * 2,
-                        '-' => -power_of_5,
-                        '0' => 0,
-                        '1' => power_of_5,
-                        '2' => power_of_5 * 2,
-                        _ => unreachable!(),
-                    };
-                    power_of_5 /= 5;
-                    power_of_5_sum -= power_of_5;
-                    digit
-                })
-                .skip_while(|digit| *digit == '0')
-                .collect::<String>()
-        )
-    }
-}
+pub type ParseSnafuError = ParseNumeralError;
 
 #[cfg(test)]
 mod tests {
@@ -121,115 +24,115 @@ mod tests {
         let test_cases = [
             TestCase {
                 input: "1",
-                output: Ok(Snafu(1)),
+                output: Ok(Snafu::from(1)),
             },
             TestCase {
                 input: "2",
-                output: Ok(Snafu(2)),
+                output: Ok(Snafu::from(2)),
             },
             TestCase {
                 input: "1=",
-                output: Ok(Snafu(3)),
+                output: Ok(Snafu::from(3)),
             },
             TestCase {
                 input: "1-",
-                output: Ok(Snafu(4)),
+                output: Ok(Snafu::from(4)),
             },
             TestCase {
                 input: "10",
-                output: Ok(Snafu(5)),
+                output: Ok(Snafu::from(5)),
             },
             TestCase {
                 input: "11",
-                output: Ok(Snafu(6)),
+                output: Ok(Snafu::from(6)),
             },
             TestCase {
                 input: "12",
-                output: Ok(Snafu(7)),
+                output: Ok(Snafu::from(7)),
             },
             TestCase {
                 input: "2=",
-                output: Ok(Snafu(8)),
+                output: Ok(Snafu::from(8)),
             },
             TestCase {
                 input: "2-",
-                output: Ok(Snafu(9)),
+                output: Ok(Snafu::from(9)),
             },
             TestCase {
                 input: "20",
-                output: Ok(Snafu(10)),
+                output: Ok(Snafu::from(10)),
             },
             TestCase {
                 input: "1=0",
-                output: Ok(Snafu(15)),
+                output: Ok(Snafu::from(15)),
             },
             TestCase {
                 input: "1-0",
-                output: Ok(Snafu(20)),
+                output: Ok(Snafu::from(20)),
             },
             TestCase {
                 input: "1=11-2",
-                output: Ok(Snafu(2022)),
+                output: Ok(Snafu::from(2022)),
             },
             TestCase {
                 input: "1-0---0",
-                output: Ok(Snafu(12345)),
+                output: Ok(Snafu::from(12345)),
             },
             TestCase {
                 input: "1121-1110-1=0",
-                output: Ok(Snafu(314159265)),
+                output: Ok(Snafu::from(314159265)),
             },
             TestCase {
                 input: "1=-0-2",
-                output: Ok(Snafu(1747)),
+                output: Ok(Snafu::from(1747)),
             },
             TestCase {
                 input: "12111",
-                output: Ok(Snafu(906)),
+                output: Ok(Snafu::from(906)),
             },
             TestCase {
                 input: "2=0=",
-                output: Ok(Snafu(198)),
+                output: Ok(Snafu::from(198)),
             },
             TestCase {
                 input: "21",
-                output: Ok(Snafu(11)),
+                output: Ok(Snafu::from(11)),
             },
             TestCase {
                 input: "2=01",
-                output: Ok(Snafu(201)),
+                output: Ok(Snafu::from(201)),
             },
             TestCase {
                 input: "111",
-                output: Ok(Snafu(31)),
+                output: Ok(Snafu::from(31)),
             },
             TestCase {
                 input: "20012",
-                output: Ok(Snafu(1257)),
+                output: Ok(Snafu::from(1257)),
             },
             TestCase {
                 input: "112",
-                output: Ok(Snafu(32)),
+                output: Ok(Snafu::from(32)),
             },
             TestCase {
                 input: "1=-1=",
-                output: Ok(Snafu(353)),
+                output: Ok(Snafu::from(353)),
             },
             TestCase {
                 input: "1-12",
-                output: Ok(Snafu(107)),
+                output: Ok(Snafu::from(107)),
             },
             TestCase {
                 input: "12",
-                output: Ok(Snafu(7)),
+                output: Ok(Snafu::from(7)),
             },
             TestCase {
                 input: "1=",
-                output: Ok(Snafu(3)),
+                output: Ok(Snafu::from(3)),
             },
             TestCase {
                 input: "122",
-                output: Ok(Snafu(37)),
+                output: Ok(Snafu::from(37)),
             },
         ];
         for tc in test_cases {
@@ -245,115 +148,115 @@ mod tests {
         }
         let test_cases = [
             TestCase {
-                input: Snafu(1),
+                input: Snafu::from(1),
                 output: "1".to_string(),
             },
             TestCase {
-                input: Snafu(2),
+                input: Snafu::from(2),
                 output: "2".to_string(),
             },
             TestCase {
-                input: Snafu(3),
+                input: Snafu::from(3),
                 output: "1=".to_string(),
             },
             TestCase {
-                input: Snafu(4),
+                input: Snafu::from(4),
                 output: "1-".to_string(),
             },
             TestCase {
-                input: Snafu(5),
+                input: Snafu::from(5),
                 output: "10".to_string(),
             },
             TestCase {
-                input: Snafu(6),
+                input: Snafu::from(6),
                 output: "11".to_string(),
             },
             TestCase {
-                input: Snafu(7),
+                input: Snafu::from(7),
                 output: "12".to_string(),
             },
             TestCase {
-                input: Snafu(8),
+                input: Snafu::from(8),
                 output: "2=".to_string(),
             },
             TestCase {
-                input: Snafu(9),
+                input: Snafu::from(9),
                 output: "2-".to_string(),
             },
             TestCase {
-                input: Snafu(10),
+                input: Snafu::from(10),
                 output: "20".to_string(),
             },
             TestCase {
-                input: Snafu(15),
+                input: Snafu::from(15),
                 output: "1=0".to_string(),
             },
             TestCase {
-                input: Snafu(20),
+                input: Snafu::from(20),
                 output: "1-0".to_string(),
             },
             TestCase {
-                input: Snafu(2022),
+                input: Snafu::from(2022),
                 output: "1=11-2".to_string(),
             },
             TestCase {
-                input: Snafu(12345),
+                input: Snafu::from(12345),
                 output: "1-0---0".to_string(),
             },
             TestCase {
-                input: Snafu(314159265),
+                input: Snafu::from(314159265),
                 output: "1121-1110-1=0".to_string(),
             },
             TestCase {
-                input: Snafu(1747),
+                input: Snafu::from(1747),
                 output: "1=-0-2".to_string(),
             },
             TestCase {
-                input: Snafu(906),
+                input: Snafu::from(906),
                 output: "12111".to_string(),
             },
             TestCase {
-                input: Snafu(198),
+                input: Snafu::from(198),
                 output: "2=0=".to_string(),
             },
             TestCase {
-                input: Snafu(11),
+                input: Snafu::from(11),
                 output: "21".to_string(),
             },
             TestCase {
-                input: Snafu(201),
+                input: Snafu::from(201),
                 output: "2=01".to_string(),
             },
             TestCase {
-                input: Snafu(31),
+                input: Snafu::from(31),
                 output: "111".to_string(),
             },
             TestCase {
-                input: Snafu(1257),
+                input: Snafu::from(1257),
                 output: "20012".to_string(),
             },
             TestCase {
-                input: Snafu(32),
+                input: Snafu::from(32),
                 output: "112".to_string(),
             },
             TestCase {
-                input: Snafu(353),
+                input: Snafu::from(353),
                 output: "1=-1=".to_string(),
             },
             TestCase {
-                input: Snafu(107),
+                input: Snafu::from(107),
                 output: "1-12".to_string(),
             },
             TestCase {
-                input: Snafu(7),
+                input: Snafu::from(7),
                 output: "12".to_string(),
             },
             TestCase {
-                input: Snafu(3),
+                input: Snafu::from(3),
                 output: "1=".to_string(),
             },
             TestCase {
-                input: Snafu(37),
+                input: Snafu::from(37),
                 output: "122".to_string(),
             },
         ];
@@ -361,4 +264,187 @@ mod tests {
             assert_eq!(tc.input.to_string(), tc.output);
         }
     }
+
+    #[test]
+    fn snafu_arithmetic() {
+        struct TestCase {
+            input: (i64, i64),
+        }
+        let test_cases = [
+            TestCase { input: (0, 0) },
+            TestCase { input: (1, 2) },
+            TestCase {
+                input: (2022, -12345),
+            },
+            TestCase {
+                input: (-314159265, 7),
+            },
+            TestCase {
+                input: (i64::MAX, 1),
+            },
+            TestCase {
+                input: (i64::MIN, -1),
+            },
+        ];
+        for tc in test_cases {
+            let (lhs, rhs) = tc.input;
+            let (a, b) = (Snafu::from(lhs), Snafu::from(rhs));
+            assert_eq!(a.checked_add(b), lhs.checked_add(rhs).map(Snafu::from));
+            assert_eq!(a.checked_sub(b), lhs.checked_sub(rhs).map(Snafu::from));
+            assert_eq!(a.checked_mul(b), lhs.checked_mul(rhs).map(Snafu::from));
+            assert_eq!(a.checked_neg(), lhs.checked_neg().map(Snafu::from));
+            if let Some(sum) = lhs.checked_add(rhs) {
+                assert_eq!(i64::from(a + b), sum);
+            }
+            if let Some(difference) = lhs.checked_sub(rhs) {
+                assert_eq!(i64::from(a - b), difference);
+            }
+            if let Some(product) = lhs.checked_mul(rhs) {
+                assert_eq!(i64::from(a * b), product);
+            }
+            if let Some(negation) = lhs.checked_neg() {
+                assert_eq!(i64::from(-a), negation);
+            }
+        }
+    }
+
+    #[test]
+    fn snafu_sum_product() {
+        struct TestCase {
+            input: Vec<i64>,
+            output: (i64, i64),
+        }
+        let test_cases = [
+            TestCase {
+                input: vec![],
+                output: (0, 1),
+            },
+            TestCase {
+                input: vec![1747, 906, 198, 11, 201, 31],
+                output: (3094, 21480121290276),
+            },
+            TestCase {
+                input: vec![-3, 5, 7],
+                output: (9, -105),
+            },
+        ];
+        for tc in test_cases {
+            let snafus =
+                tc.input.into_iter().map(Snafu::from).collect::<Vec<_>>();
+            assert_eq!(snafus.iter().sum::<Snafu>(), Snafu::from(tc.output.0));
+            assert_eq!(
+                snafus.iter().product::<Snafu>(),
+                Snafu::from(tc.output.1)
+            );
+        }
+    }
+
+    #[test]
+    fn snafu_display_edge_cases() {
+        struct TestCase {
+            input: Snafu,
+            output: String,
+        }
+        let test_cases = [
+            TestCase {
+                input: Snafu::from(0),
+                output: "0".to_string(),
+            },
+            TestCase {
+                input: Snafu::from(-1),
+                output: "-".to_string(),
+            },
+            TestCase {
+                input: Snafu::from(-2),
+                output: "=".to_string(),
+            },
+            TestCase {
+                input: Snafu::from(-3),
+                output: "-2".to_string(),
+            },
+            TestCase {
+                input: Snafu::from(-2022),
+                output: "-2--1=".to_string(),
+            },
+            TestCase {
+                input: Snafu::from(i64::MAX),
+                output: "1110--=-02=100==0-0=11=11212".to_string(),
+            },
+            TestCase {
+                input: Snafu::from(i64::MIN),
+                output: "---011210=2-00220102--2--==2".to_string(),
+            },
+        ];
+        for tc in test_cases {
+            assert_eq!(tc.input.to_string(), tc.output);
+        }
+    }
+
+    fn assert_round_trip(value: i64) {
+        let formatted = Snafu::from(value).to_string();
+        assert!(formatted == "0" || !formatted.starts_with('0'));
+        assert_eq!(formatted.parse(), Ok(Snafu::from(value)), "{}", formatted);
+    }
+
+    #[test]
+    fn snafu_round_trip_exhaustive() {
+        for value in -100_000..=100_000 {
+            assert_round_trip(value);
+        }
+        for offset in 0..10_000 {
+            assert_round_trip(i64::MIN + offset);
+            assert_round_trip(i64::MAX - offset);
+        }
+    }
+
+    #[test]
+    fn snafu_round_trip_random() {
+        let mut state = 0x2545_f491_4f6c_dd1d_u64;
+        for _ in 0..100_000 {
+            state ^= state << 13;
+            state ^= state >> 7;
+            state ^= state << 17;
+            let value = state as i64 >> (state % 64);
+            assert_round_trip(value);
+        }
+    }
+
+    #[test]
+    fn snafu_from_str_error() {
+        struct TestCase {
+            input: &'static str,
+            output: ParseSnafuError,
+        }
+        let test_cases = [
+            TestCase {
+                input: "",
+                output: ParseSnafuError::Empty,
+            },
+            TestCase {
+                input: "1=a2",
+                output: ParseSnafuError::InvalidCharacter {
+                    character: 'a',
+                    column: 3,
+                },
+            },
+            TestCase {
+                input: "12 ",
+                output: ParseSnafuError::InvalidCharacter {
+                    character: ' ',
+                    column: 3,
+                },
+            },
+            TestCase {
+                input: "2222222222222222222222222222",
+                output: ParseSnafuError::Overflow,
+            },
+            TestCase {
+                input: "1000000000000000000000000000000000000000000000000000000000000",
+                output: ParseSnafuError::Overflow,
+            },
+        ];
+        for tc in test_cases {
+            assert_eq!(tc.input.parse::<Snafu>(), Err(tc.output));
+        }
+    }
 }
@@ -0,0 +1,200 @@
+use std::collections::HashSet;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Voxel {
+    pub x: i32,
+    pub y: i32,
+    pub z: i32,
+}
+
+impl Voxel {
+    pub fn neighbours(self) -> [Voxel; 6] {
+        let Self { x, y, z } = self;
+        [
+            Self { x: x - 1, y, z },
+            Self { x: x + 1, y, z },
+            Self { x, y: y - 1, z },
+            Self { x, y: y + 1, z },
+            Self { x, y, z: z - 1 },
+            Self { x, y, z: z + 1 },
+        ]
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Bounds {
+    pub min: Voxel,
+    pub max: Voxel,
+}
+
+impl Bounds {
+    pub fn contains(&self, voxel: Voxel) -> bool {
+        (self.min.x..=self.max.x).contains(&voxel.x)
+            && (self.min.y..=self.max.y).contains(&voxel.y)
+            && (self.min.z..=self.max.z).contains(&voxel.z)
+    }
+
+    pub fn expand(self, margin: i32) -> Self {
+        Self {
+            min: Voxel {
+                x: self.min.x - margin,
+                y: self.min.y - margin,
+                z: self.min.z - margin,
+            },
+            max: Voxel {
+                x: self.max.x + margin,
+                y: self.max.y + margin,
+                z: self.max.z + margin,
+            },
+        }
+    }
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct VoxelSet {
+    voxels: HashSet<Voxel>,
+}
+
+impl VoxelSet {
+    pub fn insert(&mut self, voxel: Voxel) -> bool {
+        self.voxels.insert(voxel)
+    }
+
+    pub fn contains(&self, voxel: Voxel) -> bool {
+        self.voxels.contains(&voxel)
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = Voxel> + '_ {
+        self.voxels.iter().copied()
+    }
+
+    pub fn bounds(&self) -> Option<Bounds> {
+        let mut voxels = self.iter();
+        let first = voxels.next()?;
+        Some(voxels.fold(
+            Bounds {
+                min: first,
+                max: first,
+            },
+            |Bounds { min, max }, voxel| Bounds {
+                min: Voxel {
+                    x: min.x.min(voxel.x),
+                    y: min.y.min(voxel.y),
+                    z: min.z.min(voxel.z),
+                },
+                max: Voxel {
+                    x: max.x.max(voxel.x),
+                    y: max.y.max(voxel.y),
+                    z: max.z.max(voxel.z),
+                },
+            },
+        ))
+    }
+
+    pub fn surface_area(&self) -> usize {
+        self.iter()
+            .flat_map(Voxel::neighbours)
+            .filter(|&neighbour| !self.contains(neighbour))
+            .count()
+    }
+
+    pub fn exterior(&self) -> VoxelSet {
+        let mut exterior = VoxelSet::default();
+        let Some(bounds) = self.bounds().map(|bounds| bounds.expand(1)) else {
+            return exterior;
+        };
+        let mut stack = vec![bounds.min];
+        exterior.insert(bounds.min);
+        while let Some(voxel) = stack.pop() {
+            for neighbour in voxel.neighbours() {
+                if bounds.contains(neighbour)
+                    && !self.contains(neighbour)
+                    && exterior.insert(neighbour)
+                {
+                    stack.push(neighbour);
+                }
+            }
+        }
+        exterior
+    }
+
+    pub fn exterior_surface_area(&self) -> usize {
+        let exterior = self.exterior();
+        self.iter()
+            .flat_map(Voxel::neighbours)
+            .filter(|&neighbour| exterior.contains(neighbour))
+            .count()
+    }
+}
+
+impl FromIterator<Voxel> for VoxelSet {
+    fn from_iter<T: IntoIterator<Item = Voxel>>(iter: T) -> Self {
+        Self {
+            voxels: iter.into_iter().collect(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn voxel_set_surface_area() {
+        struct TestCase {
+            input: Vec<(i32, i32, i32)>,
+            output: (usize, usize),
+        }
+        let test_cases = [
+            TestCase {
+                input: vec![],
+                output: (0, 0),
+            },
+            TestCase {
+                input: vec![(1, 1, 1), (2, 1, 1)],
+                output: (10, 10),
+            },
+            TestCase {
+                input: vec![(1, 1, 1), (1, 1, 1)],
+                output: (6, 6),
+            },
+            TestCase {
+                input: vec![(-1, 0, 0), (1, 0, 0)],
+                output: (12, 12),
+            },
+        ];
+        for tc in test_cases {
+            let voxels = tc
+                .input
+                .into_iter()
+                .map(|(x, y, z)| Voxel { x, y, z })
+                .collect::<VoxelSet>();
+            assert_eq!(
+                (voxels.surface_area(), voxels.exterior_surface_area()),
+                tc.output
+            );
+        }
+    }
+
+    #[test]
+    fn voxel_set_exterior() {
+        let shell = (0..3)
+            .flat_map(|x| {
+                (0..3).flat_map(move |y| (0..3).map(move |z| (x, y, z)))
+            })
+            .filter(|&position| position != (1, 1, 1))
+            .map(|(x, y, z)| Voxel { x, y, z })
+            .collect::<VoxelSet>();
+        assert_eq!(shell.iter().count(), 26);
+        assert_eq!(shell.surface_area(), 60);
+        assert_eq!(shell.exterior_surface_area(), 54);
+        let exterior = shell.exterior();
+        assert_eq!(exterior.iter().count(), 5 * 5 * 5 - 27);
+        assert!(!exterior.contains(Voxel { x: 1, y: 1, z: 1 }));
+        assert!(exterior.contains(Voxel {
+            x: -1,
+            y: -1,
+            z: -1
+        }));
+    }
+}